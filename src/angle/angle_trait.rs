@@ -0,0 +1,81 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+// Crate imports
+extern crate num_traits;
+
+// Module imports
+use self::num_traits::Float;
+
+use ::angle::Rad;
+
+use std::iter::Sum;
+use std::ops::{Add, Neg, Sub};
+
+/*===============================================================================================*/
+/*------ANGLE TRAIT------------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Implemented by all angle types.
+///
+/// # Examples
+/// ```
+/// # use ion_math::angle::{Angle, Deg, Gradians, Turns};
+/// fn is_reflex<A: Angle> (angle: A) -> bool {
+///     angle > A::half_turn ()
+/// }
+///
+/// assert! (is_reflex (Deg::<f32>::new (270)));
+/// assert! (!is_reflex (Turns::<f32>::new (0.25)));
+/// assert_eq! (Gradians::<f32>::full_turn (), Gradians::new (400));
+/// ```
+pub trait Angle:
+    Copy + PartialEq + PartialOrd + Sum +
+    Add<Output = Self> + Sub<Output = Self> + Neg<Output = Self> {
+
+    /// The angle value type.
+    type ValType: Float;
+
+    /// Returns an angle of one full turn.
+    fn full_turn () -> Self;
+    /// Returns an angle of half a turn.
+    fn half_turn () -> Self;
+
+    /// Converts the angle to radians.
+    fn to_rad (&self) -> Rad<Self::ValType>;
+    /// Converts an angle in radians to this unit.
+    fn from_rad (rad: &Rad<Self::ValType>) -> Self;
+
+    /// Returns the sine of the angle.
+    fn sin (&self) -> Self::ValType {
+        self.to_rad ().value.sin ()
+    }
+
+    /// Returns the cosine of the angle.
+    fn cos (&self) -> Self::ValType {
+        self.to_rad ().value.cos ()
+    }
+
+    /// Returns the tangent of the angle.
+    fn tan (&self) -> Self::ValType {
+        self.to_rad ().value.tan ()
+    }
+
+    /// Returns the sine and cosine of the angle, computed together.
+    fn sin_cos (&self) -> (Self::ValType, Self::ValType) {
+        self.to_rad ().value.sin_cos ()
+    }
+}
@@ -20,9 +20,16 @@ extern crate num_traits;
 // Module imports
 use self::num_traits::{Float, Num, NumCast};
 
-use ::angle::Rad;
+use ::angle::{Angle, Gradians, Rad, Turns};
+use ::approx::ApproxEq;
+use ::parse::{self, ParseError};
+use ::util;
 
 use std::convert::From;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
 
 /*===============================================================================================*/
 /*------DEG STRUCT-------------------------------------------------------------------------------*/
@@ -62,10 +69,811 @@ impl<T> Deg<T> where
 
 /*-----------------------------------------------------------------------------------------------*/
 
+impl<T> From<Rad<T>> for Deg<T> where
+    T: Copy + Float + NumCast {
+
+    fn from (rad: Rad<T>) -> Deg<T> {
+        Deg {value: rad.value.to_degrees ()}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
 impl<'a, T> From<&'a Rad<T>> for Deg<T> where
     T: Copy + Float + NumCast {
 
     fn from (rad: &Rad<T>) -> Deg<T> {
-        Deg::new (rad.value * T::from (57.295779).unwrap ())
+        Deg {value: rad.value.to_degrees ()}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> From<Turns<T>> for Deg<T> where
+    T: Copy + Float + NumCast {
+
+    fn from (turns: Turns<T>) -> Deg<T> {
+        Deg {value: turns.value * T::from (360.0).unwrap ()}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> From<&'a Turns<T>> for Deg<T> where
+    T: Copy + Float + NumCast {
+
+    fn from (turns: &Turns<T>) -> Deg<T> {
+        Deg {value: turns.value * T::from (360.0).unwrap ()}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> From<Gradians<T>> for Deg<T> where
+    T: Copy + Float + NumCast {
+
+    fn from (gradians: Gradians<T>) -> Deg<T> {
+        Deg {value: gradians.value * T::from (360.0 / 400.0).unwrap ()}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> From<&'a Gradians<T>> for Deg<T> where
+    T: Copy + Float + NumCast {
+
+    fn from (gradians: &Gradians<T>) -> Deg<T> {
+        Deg {value: gradians.value * T::from (360.0 / 400.0).unwrap ()}
+    }
+}
+
+/*===============================================================================================*/
+/*------OPERATORS--------------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Add for Deg<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Deg<T>;
+
+    fn add (self, rhs: Deg<T>) -> Deg<T> {
+        Deg::new (self.value + rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Add<&'a Deg<T>> for Deg<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Deg<T>;
+
+    fn add (self, rhs: &Deg<T>) -> Deg<T> {
+        Deg::new (self.value + rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Add<Deg<T>> for &'a Deg<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Deg<T>;
+
+    fn add (self, rhs: Deg<T>) -> Deg<T> {
+        Deg::new (self.value + rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> Add<&'a Deg<T>> for &'b Deg<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Deg<T>;
+
+    fn add (self, rhs: &Deg<T>) -> Deg<T> {
+        Deg::new (self.value + rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Add<Rad<T>> for Deg<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Deg<T>;
+
+    fn add (self, rhs: Rad<T>) -> Deg<T> {
+        Deg::new (self.value + Deg::from (&rhs).value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Add<&'a Rad<T>> for Deg<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Deg<T>;
+
+    fn add (self, rhs: &Rad<T>) -> Deg<T> {
+        Deg::new (self.value + Deg::from (rhs).value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Add<Rad<T>> for &'a Deg<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Deg<T>;
+
+    fn add (self, rhs: Rad<T>) -> Deg<T> {
+        Deg::new (self.value + Deg::from (&rhs).value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> Add<&'a Rad<T>> for &'b Deg<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Deg<T>;
+
+    fn add (self, rhs: &Rad<T>) -> Deg<T> {
+        Deg::new (self.value + Deg::from (rhs).value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> AddAssign for Deg<T> where
+    T: Copy + Float + NumCast {
+
+    fn add_assign (&mut self, rhs: Deg<T>) {
+        self.value = self.value + rhs.value;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> AddAssign<Rad<T>> for Deg<T> where
+    T: Copy + Float + NumCast {
+
+    fn add_assign (&mut self, rhs: Rad<T>) {
+        self.value = self.value + Deg::from (&rhs).value;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Sub for Deg<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Deg<T>;
+
+    fn sub (self, rhs: Deg<T>) -> Deg<T> {
+        Deg::new (self.value - rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Sub<&'a Deg<T>> for Deg<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Deg<T>;
+
+    fn sub (self, rhs: &Deg<T>) -> Deg<T> {
+        Deg::new (self.value - rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Sub<Deg<T>> for &'a Deg<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Deg<T>;
+
+    fn sub (self, rhs: Deg<T>) -> Deg<T> {
+        Deg::new (self.value - rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> Sub<&'a Deg<T>> for &'b Deg<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Deg<T>;
+
+    fn sub (self, rhs: &Deg<T>) -> Deg<T> {
+        Deg::new (self.value - rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Sub<Rad<T>> for Deg<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Deg<T>;
+
+    fn sub (self, rhs: Rad<T>) -> Deg<T> {
+        Deg::new (self.value - Deg::from (&rhs).value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Sub<&'a Rad<T>> for Deg<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Deg<T>;
+
+    fn sub (self, rhs: &Rad<T>) -> Deg<T> {
+        Deg::new (self.value - Deg::from (rhs).value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Sub<Rad<T>> for &'a Deg<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Deg<T>;
+
+    fn sub (self, rhs: Rad<T>) -> Deg<T> {
+        Deg::new (self.value - Deg::from (&rhs).value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> Sub<&'a Rad<T>> for &'b Deg<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Deg<T>;
+
+    fn sub (self, rhs: &Rad<T>) -> Deg<T> {
+        Deg::new (self.value - Deg::from (rhs).value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> SubAssign for Deg<T> where
+    T: Copy + Float + NumCast {
+
+    fn sub_assign (&mut self, rhs: Deg<T>) {
+        self.value = self.value - rhs.value;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> SubAssign<Rad<T>> for Deg<T> where
+    T: Copy + Float + NumCast {
+
+    fn sub_assign (&mut self, rhs: Rad<T>) {
+        self.value = self.value - Deg::from (&rhs).value;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Mul<T> for Deg<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Deg<T>;
+
+    fn mul (self, rhs: T) -> Deg<T> {
+        Deg::new (self.value * rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Mul<T> for &'a Deg<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Deg<T>;
+
+    fn mul (self, rhs: T) -> Deg<T> {
+        Deg::new (self.value * rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> MulAssign<T> for Deg<T> where
+    T: Copy + Float + NumCast {
+
+    fn mul_assign (&mut self, rhs: T) {
+        self.value = self.value * rhs;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Div for Deg<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = T;
+
+    fn div (self, rhs: Deg<T>) -> T {
+        self.value / rhs.value
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Div<&'a Deg<T>> for Deg<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = T;
+
+    fn div (self, rhs: &Deg<T>) -> T {
+        self.value / rhs.value
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Div<Deg<T>> for &'a Deg<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = T;
+
+    fn div (self, rhs: Deg<T>) -> T {
+        self.value / rhs.value
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> Div<&'a Deg<T>> for &'b Deg<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = T;
+
+    fn div (self, rhs: &Deg<T>) -> T {
+        self.value / rhs.value
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Div<T> for Deg<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Deg<T>;
+
+    fn div (self, rhs: T) -> Deg<T> {
+        Deg::new (self.value / rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Div<T> for &'a Deg<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Deg<T>;
+
+    fn div (self, rhs: T) -> Deg<T> {
+        Deg::new (self.value / rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> DivAssign<T> for Deg<T> where
+    T: Copy + Float + NumCast {
+
+    fn div_assign (&mut self, rhs: T) {
+        self.value = self.value / rhs;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Neg for Deg<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Deg<T>;
+
+    fn neg (self) -> Deg<T> {
+        Deg::new (-self.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Neg for &'a Deg<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Deg<T>;
+
+    fn neg (self) -> Deg<T> {
+        Deg::new (-self.value)
+    }
+}
+
+/*===============================================================================================*/
+/*------TRAIT IMPLEMENTATIONS--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Angle for Deg<T> where
+    T: Copy + Float + NumCast {
+
+    type ValType = T;
+
+    fn full_turn () -> Deg<T> {
+        Deg {value: T::from (360).unwrap ()}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn half_turn () -> Deg<T> {
+        Deg {value: T::from (180).unwrap ()}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn to_rad (&self) -> Rad<T> {
+        Rad::from (self)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn from_rad (rad: &Rad<T>) -> Deg<T> {
+        Deg::from (rad)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> ApproxEq for Deg<T> where
+    T: ApproxEq + Copy + Float + NumCast {
+
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon () -> T::Epsilon {
+        T::default_epsilon ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_relative () -> T::Epsilon {
+        T::default_max_relative ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_ulps () -> u32 {
+        T::default_max_ulps ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn abs_diff_eq (&self, other: &Deg<T>, epsilon: T::Epsilon) -> bool {
+        self.value.abs_diff_eq (&other.value, epsilon)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn relative_eq (&self, other: &Deg<T>, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        self.value.relative_eq (&other.value, epsilon, max_relative)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn ulps_eq (&self, other: &Deg<T>, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        self.value.ulps_eq (&other.value, epsilon, max_ulps)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> fmt::Display for Deg<T> where
+    T: Copy + Float + fmt::Display + NumCast {
+
+    /// Formats the value of the angle followed by its unit, honouring the precision flag.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Deg;
+    /// let angle = Deg::<f32>::new (45);
+    ///
+    /// assert_eq! (format! ("{}", angle), "45°");
+    /// ```
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write! (f, "{}°", parse::format_component (f, &self.value))
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> FromStr for Deg<T> where
+    T: Copy + Float + FromStr + NumCast {
+
+    type Err = ParseError;
+
+    /// Parses an angle from a number followed by an optional unit, converting it if needed.
+    ///
+    /// See the `parse` module for the accepted units.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Deg;
+    /// let angle: Deg<f64> = "45deg".parse ().unwrap ();
+    /// assert_eq! (angle, Deg::new (45));
+    ///
+    /// let angle: Deg<f64> = "45°".parse ().unwrap ();
+    /// assert_eq! (angle, Deg::new (45));
+    ///
+    /// let angle: Deg<f64> = "0.25turn".parse ().unwrap ();
+    /// assert_eq! (angle, Deg::new (90));
+    ///
+    /// // Without a unit the value is in degrees
+    /// let angle: Deg<f64> = "45".parse ().unwrap ();
+    /// assert_eq! (angle, Deg::new (45));
+    /// ```
+    fn from_str (s: &str) -> Result<Deg<T>, ParseError> {
+        parse::parse_angle (s, |value| Deg {value})
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Sum for Deg<T> where
+    T: Copy + Float + NumCast {
+
+    /// Adds up the angles of an iterator.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Deg;
+    /// let angles = [Deg::<f32>::new (45), Deg::new (90), Deg::new (-30)];
+    ///
+    /// assert_eq! (angles.iter ().sum::<Deg<f32>> (), Deg::new (105));
+    /// assert_eq! (angles.iter ().cloned ().sum::<Deg<f32>> (), Deg::new (105));
+    /// ```
+    fn sum<I> (iter: I) -> Deg<T> where
+        I: Iterator<Item = Deg<T>> {
+
+        iter.fold (Deg::new (0), |total, angle| total + angle)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Sum<&'a Deg<T>> for Deg<T> where
+    T: Copy + Float + NumCast {
+
+    fn sum<I> (iter: I) -> Deg<T> where
+        I: Iterator<Item = &'a Deg<T>> {
+
+        iter.fold (Deg::new (0), |total, angle| total + angle)
+    }
+}
+
+/*===============================================================================================*/
+/*------PUBLIC METHODS---------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Deg<T> where
+    T: Copy + Float + NumCast {
+
+    /// Returns the sine of the angle.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Deg;
+    /// assert_eq! (Deg::<f32>::new (0).sin (), 0.0);
+    /// ```
+    pub fn sin (&self) -> T {
+        Rad::from (self).value.sin ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the cosine of the angle.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Deg;
+    /// assert_eq! (Deg::<f32>::new (0).cos (), 1.0);
+    /// ```
+    pub fn cos (&self) -> T {
+        Rad::from (self).value.cos ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the tangent of the angle.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Deg;
+    /// assert_eq! (Deg::<f32>::new (0).tan (), 0.0);
+    /// ```
+    pub fn tan (&self) -> T {
+        Rad::from (self).value.tan ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the sine and cosine of the angle, computed together.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Deg;
+    /// let (sin, cos) = Deg::<f64>::new (90).sin_cos ();
+    ///
+    /// assert! ((sin - 1.0).abs () < 1e-9 && cos.abs () < 1e-9);
+    /// ```
+    pub fn sin_cos (&self) -> (T, T) {
+        Rad::from (self).value.sin_cos ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Wraps the angle to the range [0°, 360°).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Deg;
+    /// # fn main () {
+    /// assert_approx_eq! (Deg::<f64>::new (-90).normalize (), Deg::new (270));
+    /// # }
+    /// ```
+    pub fn normalize (&self) -> Deg<T> {
+
+        let full_turn = Deg::full_turn ().value;
+        let mut value = self.value % full_turn;
+
+        if value < T::zero () {
+            value = value + full_turn;
+        }
+
+        // Adding a full turn to a tiny negative value can round up to a full turn
+        if value >= full_turn {
+            value = T::zero ();
+        }
+
+        Deg {value}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Wraps the angle to the range (-180°, 180°].
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Deg;
+    /// # fn main () {
+    /// assert_approx_eq! (Deg::<f64>::new (270).normalize_signed (), Deg::new (-90));
+    /// # }
+    /// ```
+    pub fn normalize_signed (&self) -> Deg<T> {
+
+        let angle = self.normalize ();
+
+        if angle > Deg::half_turn () {
+            angle - Deg::full_turn ()
+        } else {
+            angle
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the signed difference to another angle, taking the shortest way around.
+    ///
+    /// The result is in the range (-180°, 180°].
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Deg;
+    /// # fn main () {
+    /// let delta = Deg::<f64>::new (350).shortest_delta (&Deg::new (10));
+    /// assert_approx_eq! (delta.value, 20.0);
+    /// # }
+    /// ```
+    pub fn shortest_delta (&self, to: &Deg<T>) -> Deg<T> {
+        (to - self).normalize_signed ()
+    }
+}
+
+/*===============================================================================================*/
+/*------PUBLIC STATIC METHODS--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Deg<T> where
+    T: Copy + Float + NumCast {
+
+    /// Linearly interpolates between two angles, taking the shortest way around.
+    ///
+    /// The result is not wrapped, so it may lie outside [0°, 360°).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Deg;
+    /// # fn main () {
+    /// let angle = Deg::lerp_angle (&Deg::<f64>::new (350), &Deg::new (10), 0.5);
+    /// assert_approx_eq! (angle, Deg::new (360));
+    /// # }
+    /// ```
+    pub fn lerp_angle (from: &Deg<T>, to: &Deg<T>, percentage: T) -> Deg<T> {
+        from + from.shortest_delta (to) * percentage
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Moves an angle towards a target by at most `max_delta`, taking the shortest path.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Deg;
+    /// let current = Deg::<f64>::new (170.0);
+    /// let target = Deg::<f64>::new (-170.0);
+    ///
+    /// // Moves forwards across the wrap-around point instead of backwards
+    /// let angle = Deg::move_towards (&current, &target, &Deg::new (5.0));
+    /// assert! (angle.value > current.value);
+    /// ```
+    pub fn move_towards (current: &Deg<T>, target: &Deg<T>, max_delta: &Deg<T>) -> Deg<T> {
+
+        let target = current.value + current.shortest_delta (target).value;
+        Deg {value: util::move_towards (current.value, target, max_delta.value)}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Smoothly moves an angle towards a target using a critically damped spring, <br>
+    /// taking the shortest path.
+    ///
+    /// `velocity` is measured in degrees per second. See `util::smooth_damp` for details.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Deg;
+    /// let mut angle = Deg::<f32>::new (170.0);
+    /// let mut velocity = Deg::new (0.0);
+    ///
+    /// angle = Deg::smooth_damp (&angle, &Deg::new (-170.0), &mut velocity, 0.2, 1.0 / 60.0);
+    /// ```
+    pub fn smooth_damp (current: &Deg<T>, target: &Deg<T>, velocity: &mut Deg<T>,
+                        smooth_time: T, delta_time: T) -> Deg<T> {
+
+        let target = current.value + current.shortest_delta (target).value;
+        Deg {value: util::smooth_damp (current.value, target, &mut velocity.value,
+                                       smooth_time, delta_time)}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Moves an angle towards a target with frame-rate independent exponential decay, <br>
+    /// taking the shortest path.
+    ///
+    /// See `util::damp` for details.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Deg;
+    /// let angle = Deg::<f32>::new (170.0);
+    /// let angle = Deg::damp (&angle, &Deg::new (-170.0), 5.0, 1.0 / 60.0);
+    /// ```
+    pub fn damp (current: &Deg<T>, target: &Deg<T>, rate: T, delta_time: T) -> Deg<T> {
+
+        let target = current.value + current.shortest_delta (target).value;
+        Deg {value: util::damp (current.value, target, rate, delta_time)}
     }
 }
@@ -0,0 +1,376 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+// Crate imports
+extern crate num_traits;
+
+// Module imports
+use self::num_traits::Float;
+
+use ::angle::{Deg, Rad};
+use ::approx::ApproxEq;
+use ::matrix::{Mat3, Mat4};
+use ::quaternion::Quat;
+
+/*===============================================================================================*/
+/*------EULER ORDER ENUM-------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The order in which the rotations of an `EulerAngles` are applied.
+///
+/// Intrinsic orders rotate around the axes of the rotating object, while extrinsic <br>
+/// orders rotate around the fixed world axes. An intrinsic order is the same rotation <br>
+/// as the reversed extrinsic order, so `IntrinsicXYZ` matches `ExtrinsicZYX`.
+#[cfg_attr (feature = "serde_serialize", derive (Deserialize, Serialize))]
+#[derive (Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum EulerOrder {
+
+    /// Rotates around X, then the rotated Y, then the rotated Z axis.
+    IntrinsicXYZ,
+    /// Rotates around X, then the rotated Z, then the rotated Y axis.
+    IntrinsicXZY,
+    /// Rotates around Y, then the rotated X, then the rotated Z axis.
+    IntrinsicYXZ,
+    /// Rotates around Y, then the rotated Z, then the rotated X axis.
+    IntrinsicYZX,
+    /// Rotates around Z, then the rotated X, then the rotated Y axis.
+    IntrinsicZXY,
+    /// Rotates around Z, then the rotated Y, then the rotated X axis.
+    IntrinsicZYX,
+    /// Rotates around the fixed X, then Y, then Z axis.
+    ExtrinsicXYZ,
+    /// Rotates around the fixed X, then Z, then Y axis.
+    ExtrinsicXZY,
+    /// Rotates around the fixed Y, then X, then Z axis.
+    ExtrinsicYXZ,
+    /// Rotates around the fixed Y, then Z, then X axis.
+    ExtrinsicYZX,
+    /// Rotates around the fixed Z, then X, then Y axis.
+    ExtrinsicZXY,
+    /// Rotates around the fixed Z, then Y, then X axis.
+    ExtrinsicZYX,
+}
+
+/*===============================================================================================*/
+/*------EULER ANGLES STRUCT----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// A rotation stored as three angles around the x, y and z axes.
+///
+/// The `order` decides how the three rotations are combined.
+#[cfg_attr (feature = "serde_serialize", derive (Deserialize, Serialize))]
+#[derive (Copy, Clone, Debug, PartialEq)]
+pub struct EulerAngles<T> where
+    T: Default + Float {
+
+    // Public
+    /// The rotation around the x-axis.
+    pub x: Rad<T>,
+    /// The rotation around the y-axis.
+    pub y: Rad<T>,
+    /// The rotation around the z-axis.
+    pub z: Rad<T>,
+    /// The order the rotations are applied in.
+    pub order: EulerOrder,
+}
+
+/*===============================================================================================*/
+/*------CONSTRUCTORS-----------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> EulerAngles<T> where
+    T: Default + Float {
+
+    /// Returns a new `EulerAngles` instance.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::{EulerAngles, EulerOrder, Rad};
+    /// let euler = EulerAngles::<f32>::new (Rad::new (0.5), Rad::new (1.0), Rad::new (0.0),
+    ///                                      EulerOrder::IntrinsicYXZ);
+    /// ```
+    pub fn new (x: Rad<T>, y: Rad<T>, z: Rad<T>, order: EulerOrder) -> EulerAngles<T> {
+        EulerAngles {x, y, z, order}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a new `EulerAngles` instance from angles in degrees.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::{Deg, EulerAngles, EulerOrder};
+    /// let euler = EulerAngles::<f32>::from_degrees (Deg::new (30), Deg::new (45), Deg::new (0),
+    ///                                               EulerOrder::IntrinsicYXZ);
+    /// ```
+    pub fn from_degrees (x: Deg<T>, y: Deg<T>, z: Deg<T>, order: EulerOrder) -> EulerAngles<T> {
+
+        EulerAngles {x: Rad {value: x.value.to_radians ()},
+                     y: Rad {value: y.value.to_radians ()},
+                     z: Rad {value: z.value.to_radians ()},
+                     order}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Extracts the angles of a rotation matrix in the given order.
+    ///
+    /// The middle angle is in the range [-π/2, π/2] and the others in [-π, π]. <br>
+    /// In gimbal lock, where the first and last axes line up, the angle of the first <br>
+    /// applied rotation is set to zero and the last one holds the combined rotation.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::{EulerAngles, EulerOrder, Rad};
+    /// # use ion_math::matrix::Mat3;
+    /// # fn main () {
+    /// let euler = EulerAngles::<f64>::new (Rad::new (0.1), Rad::new (0.2), Rad::new (0.3),
+    ///                                      EulerOrder::ExtrinsicXYZ);
+    /// let mat = Mat3::from (&euler);
+    ///
+    /// assert_approx_eq! (EulerAngles::from_mat3 (&mat, EulerOrder::ExtrinsicXYZ), euler);
+    /// # }
+    /// ```
+    pub fn from_mat3 (mat: &Mat3<T>, order: EulerOrder) -> EulerAngles<T> {
+
+        from_rotation_matrix ([[mat[(0, 0)], mat[(0, 1)], mat[(0, 2)]],
+                               [mat[(1, 0)], mat[(1, 1)], mat[(1, 2)]],
+                               [mat[(2, 0)], mat[(2, 1)], mat[(2, 2)]]],
+                              order)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Extracts the angles of the rotation part of a transformation matrix in the given order.
+    ///
+    /// See `from_mat3` for the ranges of the angles.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::{EulerAngles, EulerOrder};
+    /// # use ion_math::matrix::{Mat4, MatTrait};
+    /// let euler = EulerAngles::<f32>::from_mat4 (&Mat4::identity (), EulerOrder::IntrinsicZYX);
+    ///
+    /// assert_eq! (euler.x.value, 0.0);
+    /// ```
+    pub fn from_mat4 (mat: &Mat4<T>, order: EulerOrder) -> EulerAngles<T> {
+
+        from_rotation_matrix ([[mat[(0, 0)], mat[(0, 1)], mat[(0, 2)]],
+                               [mat[(1, 0)], mat[(1, 1)], mat[(1, 2)]],
+                               [mat[(2, 0)], mat[(2, 1)], mat[(2, 2)]]],
+                              order)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Extracts the angles of a unit quaternion in the given order.
+    ///
+    /// See `from_mat3` for the ranges of the angles.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::{EulerAngles, EulerOrder, Rad};
+    /// # use ion_math::quaternion::Quat;
+    /// # fn main () {
+    /// let quat = Quat::<f64>::from_rotation_y (Rad::new (0.7));
+    /// let euler = EulerAngles::from_quat (&quat, EulerOrder::IntrinsicYXZ);
+    ///
+    /// assert_approx_eq! (euler.y, Rad::new (0.7));
+    /// # }
+    /// ```
+    pub fn from_quat (quat: &Quat<T>, order: EulerOrder) -> EulerAngles<T> {
+        EulerAngles::from_mat3 (&Mat3::from (quat), order)
+    }
+}
+
+/*===============================================================================================*/
+/*------TRAIT IMPLEMENTATIONS--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> ApproxEq for EulerAngles<T> where
+    T: ApproxEq + Default + Float {
+
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon () -> T::Epsilon {
+        T::default_epsilon ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_relative () -> T::Epsilon {
+        T::default_max_relative ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_ulps () -> u32 {
+        T::default_max_ulps ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn abs_diff_eq (&self, other: &EulerAngles<T>, epsilon: T::Epsilon) -> bool {
+
+        self.order == other.order &&
+        self.x.abs_diff_eq (&other.x, epsilon) &&
+        self.y.abs_diff_eq (&other.y, epsilon) &&
+        self.z.abs_diff_eq (&other.z, epsilon)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn relative_eq (&self, other: &EulerAngles<T>, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+
+        self.order == other.order &&
+        self.x.relative_eq (&other.x, epsilon, max_relative) &&
+        self.y.relative_eq (&other.y, epsilon, max_relative) &&
+        self.z.relative_eq (&other.z, epsilon, max_relative)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn ulps_eq (&self, other: &EulerAngles<T>, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+
+        self.order == other.order &&
+        self.x.ulps_eq (&other.x, epsilon, max_ulps) &&
+        self.y.ulps_eq (&other.y, epsilon, max_ulps) &&
+        self.z.ulps_eq (&other.z, epsilon, max_ulps)
+    }
+}
+
+/*===============================================================================================*/
+/*------PUBLIC METHODS---------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> EulerAngles<T> where
+    T: Default + Float {
+
+    /// Returns the angles in degrees as (x, y, z).
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::{EulerAngles, EulerOrder, Rad};
+    /// let euler = EulerAngles::<f32>::new (Rad::new (0.0), Rad::new (0.0), Rad::new (0.0),
+    ///                                      EulerOrder::IntrinsicXYZ);
+    /// let (x, y, z) = euler.to_degrees ();
+    /// ```
+    pub fn to_degrees (&self) -> (Deg<T>, Deg<T>, Deg<T>) {
+
+        (Deg {value: self.x.value.to_degrees ()},
+         Deg {value: self.y.value.to_degrees ()},
+         Deg {value: self.z.value.to_degrees ()})
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the angles around the fixed axes in the order they are applied.
+    ///
+    /// The first value is the axis index, where 0 is x, 1 is y and 2 is z.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::{EulerAngles, EulerOrder, Rad};
+    /// let euler = EulerAngles::<f32>::new (Rad::new (1.0), Rad::new (2.0), Rad::new (3.0),
+    ///                                      EulerOrder::IntrinsicXYZ);
+    /// let rotations = euler.extrinsic_rotations ();
+    ///
+    /// assert_eq! (rotations[0], (2, Rad::new (3.0)));
+    /// ```
+    pub fn extrinsic_rotations (&self) -> [(usize, Rad<T>); 3] {
+
+        let angles = [self.x, self.y, self.z];
+        let [i, j, k] = self.order.extrinsic_axes ();
+
+        [(i, angles[i]), (j, angles[j]), (k, angles[k])]
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl EulerOrder {
+
+    /// Returns the axes rotated around, in the order they are applied to fixed axes.
+    ///
+    /// 0 is the x-axis, 1 is the y-axis and 2 is the z-axis.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::EulerOrder;
+    /// assert_eq! (EulerOrder::IntrinsicYXZ.extrinsic_axes (), [2, 0, 1]);
+    /// assert_eq! (EulerOrder::ExtrinsicYXZ.extrinsic_axes (), [1, 0, 2]);
+    /// ```
+    pub fn extrinsic_axes (&self) -> [usize; 3] {
+
+        match *self {
+
+            EulerOrder::IntrinsicXYZ => [2, 1, 0],
+            EulerOrder::IntrinsicXZY => [1, 2, 0],
+            EulerOrder::IntrinsicYXZ => [2, 0, 1],
+            EulerOrder::IntrinsicYZX => [0, 2, 1],
+            EulerOrder::IntrinsicZXY => [1, 0, 2],
+            EulerOrder::IntrinsicZYX => [0, 1, 2],
+            EulerOrder::ExtrinsicXYZ => [0, 1, 2],
+            EulerOrder::ExtrinsicXZY => [0, 2, 1],
+            EulerOrder::ExtrinsicYXZ => [1, 0, 2],
+            EulerOrder::ExtrinsicYZX => [1, 2, 0],
+            EulerOrder::ExtrinsicZXY => [2, 0, 1],
+            EulerOrder::ExtrinsicZYX => [2, 1, 0],
+        }
+    }
+}
+
+/*===============================================================================================*/
+/*------PRIVATE FUNCTIONS------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// Extracts Euler angles from a row-major rotation matrix.
+//
+// Every order is treated as the extrinsic rotation R = Rk (c) * Rj (b) * Ri (a), whose terms
+// only differ from the XYZ case by the sign of the permutation.
+fn from_rotation_matrix<T> (m: [[T; 3]; 3], order: EulerOrder) -> EulerAngles<T> where
+    T: Default + Float {
+
+    let [i, j, k] = order.extrinsic_axes ();
+    let sign = if (j + 3 - i) % 3 == 1 {T::one ()} else {-T::one ()};
+
+    let cos_b = (m[k][j] * m[k][j] + m[k][k] * m[k][k]).sqrt ();
+    let b = (-sign * m[k][i]).atan2 (cos_b);
+
+    let (a, c) = if cos_b > T::epsilon () * T::from (16.0).unwrap () {
+
+        ((sign * m[k][j]).atan2 (m[k][k]),
+         (sign * m[j][i]).atan2 (m[i][i]))
+
+    } else {
+
+        // Gimbal lock, so only the sum or difference of a and c is known
+        (T::zero (),
+         (-sign * m[i][j]).atan2 (m[j][j]))
+    };
+
+    let mut angles = [T::zero (); 3];
+    angles[i] = a;
+    angles[j] = b;
+    angles[k] = c;
+
+    EulerAngles {x: Rad {value: angles[0]},
+                 y: Rad {value: angles[1]},
+                 z: Rad {value: angles[2]},
+                 order}
+}
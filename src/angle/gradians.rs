@@ -0,0 +1,535 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+// Crate imports
+extern crate num_traits;
+
+// Module imports
+use self::num_traits::{Float, Num, NumCast};
+
+use ::angle::{Angle, Deg, Rad, Turns};
+use ::approx::ApproxEq;
+use ::parse::{self, ParseError};
+
+use std::convert::From;
+use std::f64::consts;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/*===============================================================================================*/
+/*------GRADIANS STRUCT--------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Stores a value in gradians.
+#[cfg_attr (feature = "serde_serialize", derive (Deserialize, Serialize))]
+#[derive (Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    // Public
+    /// The value of the gradian.
+    pub value: T,
+}
+
+/*===============================================================================================*/
+/*------CONSTRUCTORS-----------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    /// Returns a new `Gradians` instance.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Gradians;
+    /// let angle = Gradians::<f32>::new (100);
+    /// ```
+    pub fn new<C> (value: C) -> Gradians<T> where
+        C: Num + NumCast {
+
+        Gradians {value: T::from (value).unwrap ()}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> From<Rad<T>> for Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    fn from (rad: Rad<T>) -> Gradians<T> {
+        Gradians {value: rad.value * T::from (400.0 / consts::TAU).unwrap ()}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> From<&'a Rad<T>> for Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    fn from (rad: &Rad<T>) -> Gradians<T> {
+        Gradians {value: rad.value * T::from (400.0 / consts::TAU).unwrap ()}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> From<Deg<T>> for Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    fn from (deg: Deg<T>) -> Gradians<T> {
+        Gradians {value: deg.value * T::from (400.0 / 360.0).unwrap ()}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> From<&'a Deg<T>> for Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    fn from (deg: &Deg<T>) -> Gradians<T> {
+        Gradians {value: deg.value * T::from (400.0 / 360.0).unwrap ()}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> From<Turns<T>> for Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    fn from (turns: Turns<T>) -> Gradians<T> {
+        Gradians {value: turns.value * T::from (400.0).unwrap ()}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> From<&'a Turns<T>> for Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    fn from (turns: &Turns<T>) -> Gradians<T> {
+        Gradians {value: turns.value * T::from (400.0).unwrap ()}
+    }
+}
+
+/*===============================================================================================*/
+/*------OPERATORS--------------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Add for Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Gradians<T>;
+
+    fn add (self, rhs: Gradians<T>) -> Gradians<T> {
+        Gradians::new (self.value + rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Add<&'a Gradians<T>> for Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Gradians<T>;
+
+    fn add (self, rhs: &Gradians<T>) -> Gradians<T> {
+        Gradians::new (self.value + rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Add<Gradians<T>> for &'a Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Gradians<T>;
+
+    fn add (self, rhs: Gradians<T>) -> Gradians<T> {
+        Gradians::new (self.value + rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> Add<&'a Gradians<T>> for &'b Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Gradians<T>;
+
+    fn add (self, rhs: &Gradians<T>) -> Gradians<T> {
+        Gradians::new (self.value + rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> AddAssign for Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    fn add_assign (&mut self, rhs: Gradians<T>) {
+        self.value = self.value + rhs.value;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Sub for Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Gradians<T>;
+
+    fn sub (self, rhs: Gradians<T>) -> Gradians<T> {
+        Gradians::new (self.value - rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Sub<&'a Gradians<T>> for Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Gradians<T>;
+
+    fn sub (self, rhs: &Gradians<T>) -> Gradians<T> {
+        Gradians::new (self.value - rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Sub<Gradians<T>> for &'a Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Gradians<T>;
+
+    fn sub (self, rhs: Gradians<T>) -> Gradians<T> {
+        Gradians::new (self.value - rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> Sub<&'a Gradians<T>> for &'b Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Gradians<T>;
+
+    fn sub (self, rhs: &Gradians<T>) -> Gradians<T> {
+        Gradians::new (self.value - rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> SubAssign for Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    fn sub_assign (&mut self, rhs: Gradians<T>) {
+        self.value = self.value - rhs.value;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Mul<T> for Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Gradians<T>;
+
+    fn mul (self, rhs: T) -> Gradians<T> {
+        Gradians::new (self.value * rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Mul<T> for &'a Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Gradians<T>;
+
+    fn mul (self, rhs: T) -> Gradians<T> {
+        Gradians::new (self.value * rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> MulAssign<T> for Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    fn mul_assign (&mut self, rhs: T) {
+        self.value = self.value * rhs;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Div for Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = T;
+
+    fn div (self, rhs: Gradians<T>) -> T {
+        self.value / rhs.value
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Div<&'a Gradians<T>> for Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = T;
+
+    fn div (self, rhs: &Gradians<T>) -> T {
+        self.value / rhs.value
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Div<Gradians<T>> for &'a Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = T;
+
+    fn div (self, rhs: Gradians<T>) -> T {
+        self.value / rhs.value
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> Div<&'a Gradians<T>> for &'b Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = T;
+
+    fn div (self, rhs: &Gradians<T>) -> T {
+        self.value / rhs.value
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Div<T> for Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Gradians<T>;
+
+    fn div (self, rhs: T) -> Gradians<T> {
+        Gradians::new (self.value / rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Div<T> for &'a Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Gradians<T>;
+
+    fn div (self, rhs: T) -> Gradians<T> {
+        Gradians::new (self.value / rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> DivAssign<T> for Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    fn div_assign (&mut self, rhs: T) {
+        self.value = self.value / rhs;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Neg for Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Gradians<T>;
+
+    fn neg (self) -> Gradians<T> {
+        Gradians::new (-self.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Neg for &'a Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Gradians<T>;
+
+    fn neg (self) -> Gradians<T> {
+        Gradians::new (-self.value)
+    }
+}
+
+/*===============================================================================================*/
+/*------TRAIT IMPLEMENTATIONS--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Angle for Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    type ValType = T;
+
+    fn full_turn () -> Gradians<T> {
+        Gradians {value: T::from (400).unwrap ()}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn half_turn () -> Gradians<T> {
+        Gradians {value: T::from (200).unwrap ()}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn to_rad (&self) -> Rad<T> {
+        Rad::from (self)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn from_rad (rad: &Rad<T>) -> Gradians<T> {
+        Gradians::from (rad)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> ApproxEq for Gradians<T> where
+    T: ApproxEq + Copy + Float + NumCast {
+
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon () -> T::Epsilon {
+        T::default_epsilon ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_relative () -> T::Epsilon {
+        T::default_max_relative ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_ulps () -> u32 {
+        T::default_max_ulps ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn abs_diff_eq (&self, other: &Gradians<T>, epsilon: T::Epsilon) -> bool {
+        self.value.abs_diff_eq (&other.value, epsilon)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn relative_eq (&self, other: &Gradians<T>, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        self.value.relative_eq (&other.value, epsilon, max_relative)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn ulps_eq (&self, other: &Gradians<T>, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        self.value.ulps_eq (&other.value, epsilon, max_ulps)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> fmt::Display for Gradians<T> where
+    T: Copy + Float + fmt::Display + NumCast {
+
+    /// Formats the value of the angle followed by its unit, honouring the precision flag.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Gradians;
+    /// let angle = Gradians::<f32>::new (50);
+    ///
+    /// assert_eq! (format! ("{}", angle), "50grad");
+    /// ```
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write! (f, "{}grad", parse::format_component (f, &self.value))
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> FromStr for Gradians<T> where
+    T: Copy + Float + FromStr + NumCast {
+
+    type Err = ParseError;
+
+    /// Parses an angle from a number followed by an optional unit, converting it if needed.
+    ///
+    /// See the `parse` module for the accepted units.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Gradians;
+    /// let angle: Gradians<f64> = "45deg".parse ().unwrap ();
+    /// assert_eq! (angle, Gradians::new (50));
+    /// ```
+    fn from_str (s: &str) -> Result<Gradians<T>, ParseError> {
+        parse::parse_angle (s, |value| Gradians {value})
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Sum for Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    /// Adds up the angles of an iterator.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Gradians;
+    /// let angles = [Gradians::<f32>::new (50), Gradians::new (100), Gradians::new (-25)];
+    ///
+    /// assert_eq! (angles.iter ().sum::<Gradians<f32>> (), Gradians::new (125));
+    /// assert_eq! (angles.iter ().cloned ().sum::<Gradians<f32>> (), Gradians::new (125));
+    /// ```
+    fn sum<I> (iter: I) -> Gradians<T> where
+        I: Iterator<Item = Gradians<T>> {
+
+        iter.fold (Gradians::new (0), |total, angle| total + angle)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Sum<&'a Gradians<T>> for Gradians<T> where
+    T: Copy + Float + NumCast {
+
+    fn sum<I> (iter: I) -> Gradians<T> where
+        I: Iterator<Item = &'a Gradians<T>> {
+
+        iter.fold (Gradians::new (0), |total, angle| total + angle)
+    }
+}
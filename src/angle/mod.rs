@@ -19,9 +19,17 @@
 /*===============================================================================================*/
 
 // Modules
+mod angle_trait;
 mod deg;
+mod euler;
+mod gradians;
 mod rad;
+mod turns;
 
 // Module imports
+pub use self::angle_trait::Angle;
 pub use self::deg::Deg;
+pub use self::euler::{EulerAngles, EulerOrder};
+pub use self::gradians::Gradians;
 pub use self::rad::Rad;
+pub use self::turns::Turns;
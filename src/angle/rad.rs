@@ -20,9 +20,17 @@ extern crate num_traits;
 // Module imports
 use self::num_traits::{Float, Num, NumCast};
 
-use ::angle::Deg;
+use ::angle::{Angle, Deg, Gradians, Turns};
+use ::approx::ApproxEq;
+use ::parse::{self, ParseError};
+use ::util;
 
 use std::convert::From;
+use std::f64::consts;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
 
 /*===============================================================================================*/
 /*------RAD STRUCT-------------------------------------------------------------------------------*/
@@ -58,6 +66,108 @@ impl<T> Rad<T> where
 
         Rad {value: T::from (value).unwrap ()}
     }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the angle whose sine is a value, in the range [-π/2, π/2].
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Rad;
+    /// assert_eq! (Rad::<f32>::asin (0.0), Rad::new (0.0));
+    /// ```
+    pub fn asin (value: T) -> Rad<T> {
+        Rad {value: value.asin ()}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the angle whose cosine is a value, in the range [0, π].
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Rad;
+    /// assert_eq! (Rad::<f32>::acos (1.0), Rad::new (0.0));
+    /// ```
+    pub fn acos (value: T) -> Rad<T> {
+        Rad {value: value.acos ()}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the angle whose tangent is a value, in the range [-π/2, π/2].
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Rad;
+    /// assert_eq! (Rad::<f32>::atan (0.0), Rad::new (0.0));
+    /// ```
+    pub fn atan (value: T) -> Rad<T> {
+        Rad {value: value.atan ()}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the angle of the point (x, y) from the positive x-axis, in the range [-π, π].
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Rad;
+    /// assert_eq! (Rad::<f64>::atan2 (1.0, 0.0), Rad::new (std::f64::consts::FRAC_PI_2));
+    /// ```
+    pub fn atan2 (y: T, x: T) -> Rad<T> {
+        Rad {value: y.atan2 (x)}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Rad<f32> {
+
+    /// Half a turn, π.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::{Deg, Rad};
+    /// assert_eq! (Deg::from (Rad::<f32>::PI), Deg::new (180));
+    /// ```
+    pub const PI: Rad<f32> = Rad {value: std::f32::consts::PI};
+    /// A quarter turn, π/2.
+    pub const FRAC_PI_2: Rad<f32> = Rad {value: std::f32::consts::FRAC_PI_2};
+    /// An eighth of a turn, π/4.
+    pub const FRAC_PI_4: Rad<f32> = Rad {value: std::f32::consts::FRAC_PI_4};
+    /// A full turn, 2π.
+    pub const TAU: Rad<f32> = Rad {value: std::f32::consts::TAU};
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Rad<f64> {
+
+    /// Half a turn, π.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::{Deg, Rad};
+    /// assert_eq! (Deg::from (Rad::<f64>::PI), Deg::new (180));
+    /// ```
+    pub const PI: Rad<f64> = Rad {value: std::f64::consts::PI};
+    /// A quarter turn, π/2.
+    pub const FRAC_PI_2: Rad<f64> = Rad {value: std::f64::consts::FRAC_PI_2};
+    /// An eighth of a turn, π/4.
+    pub const FRAC_PI_4: Rad<f64> = Rad {value: std::f64::consts::FRAC_PI_4};
+    /// A full turn, 2π.
+    pub const TAU: Rad<f64> = Rad {value: std::f64::consts::TAU};
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> From<Deg<T>> for Rad<T> where
+    T: Copy + Float + NumCast {
+
+    fn from (deg: Deg<T>) -> Rad<T> {
+        Rad {value: deg.value.to_radians ()}
+    }
 }
 
 /*-----------------------------------------------------------------------------------------------*/
@@ -66,6 +176,794 @@ impl<'a, T> From<&'a Deg<T>> for Rad<T> where
     T: Copy + Float + NumCast {
 
     fn from (deg: &Deg<T>) -> Rad<T> {
-        Rad::new (deg.value * T::from (0.017453).unwrap ())
+        Rad {value: deg.value.to_radians ()}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> From<Turns<T>> for Rad<T> where
+    T: Copy + Float + NumCast {
+
+    fn from (turns: Turns<T>) -> Rad<T> {
+        Rad {value: turns.value * T::from (consts::TAU).unwrap ()}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> From<&'a Turns<T>> for Rad<T> where
+    T: Copy + Float + NumCast {
+
+    fn from (turns: &Turns<T>) -> Rad<T> {
+        Rad {value: turns.value * T::from (consts::TAU).unwrap ()}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> From<Gradians<T>> for Rad<T> where
+    T: Copy + Float + NumCast {
+
+    fn from (gradians: Gradians<T>) -> Rad<T> {
+        Rad {value: gradians.value * T::from (consts::TAU / 400.0).unwrap ()}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> From<&'a Gradians<T>> for Rad<T> where
+    T: Copy + Float + NumCast {
+
+    fn from (gradians: &Gradians<T>) -> Rad<T> {
+        Rad {value: gradians.value * T::from (consts::TAU / 400.0).unwrap ()}
+    }
+}
+
+/*===============================================================================================*/
+/*------OPERATORS--------------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Add for Rad<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Rad<T>;
+
+    fn add (self, rhs: Rad<T>) -> Rad<T> {
+        Rad::new (self.value + rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Add<&'a Rad<T>> for Rad<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Rad<T>;
+
+    fn add (self, rhs: &Rad<T>) -> Rad<T> {
+        Rad::new (self.value + rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Add<Rad<T>> for &'a Rad<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Rad<T>;
+
+    fn add (self, rhs: Rad<T>) -> Rad<T> {
+        Rad::new (self.value + rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> Add<&'a Rad<T>> for &'b Rad<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Rad<T>;
+
+    fn add (self, rhs: &Rad<T>) -> Rad<T> {
+        Rad::new (self.value + rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Add<Deg<T>> for Rad<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Rad<T>;
+
+    fn add (self, rhs: Deg<T>) -> Rad<T> {
+        Rad::new (self.value + Rad::from (&rhs).value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Add<&'a Deg<T>> for Rad<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Rad<T>;
+
+    fn add (self, rhs: &Deg<T>) -> Rad<T> {
+        Rad::new (self.value + Rad::from (rhs).value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Add<Deg<T>> for &'a Rad<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Rad<T>;
+
+    fn add (self, rhs: Deg<T>) -> Rad<T> {
+        Rad::new (self.value + Rad::from (&rhs).value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> Add<&'a Deg<T>> for &'b Rad<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Rad<T>;
+
+    fn add (self, rhs: &Deg<T>) -> Rad<T> {
+        Rad::new (self.value + Rad::from (rhs).value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> AddAssign for Rad<T> where
+    T: Copy + Float + NumCast {
+
+    fn add_assign (&mut self, rhs: Rad<T>) {
+        self.value = self.value + rhs.value;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> AddAssign<Deg<T>> for Rad<T> where
+    T: Copy + Float + NumCast {
+
+    fn add_assign (&mut self, rhs: Deg<T>) {
+        self.value = self.value + Rad::from (&rhs).value;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Sub for Rad<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Rad<T>;
+
+    fn sub (self, rhs: Rad<T>) -> Rad<T> {
+        Rad::new (self.value - rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Sub<&'a Rad<T>> for Rad<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Rad<T>;
+
+    fn sub (self, rhs: &Rad<T>) -> Rad<T> {
+        Rad::new (self.value - rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Sub<Rad<T>> for &'a Rad<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Rad<T>;
+
+    fn sub (self, rhs: Rad<T>) -> Rad<T> {
+        Rad::new (self.value - rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> Sub<&'a Rad<T>> for &'b Rad<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Rad<T>;
+
+    fn sub (self, rhs: &Rad<T>) -> Rad<T> {
+        Rad::new (self.value - rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Sub<Deg<T>> for Rad<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Rad<T>;
+
+    fn sub (self, rhs: Deg<T>) -> Rad<T> {
+        Rad::new (self.value - Rad::from (&rhs).value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Sub<&'a Deg<T>> for Rad<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Rad<T>;
+
+    fn sub (self, rhs: &Deg<T>) -> Rad<T> {
+        Rad::new (self.value - Rad::from (rhs).value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Sub<Deg<T>> for &'a Rad<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Rad<T>;
+
+    fn sub (self, rhs: Deg<T>) -> Rad<T> {
+        Rad::new (self.value - Rad::from (&rhs).value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> Sub<&'a Deg<T>> for &'b Rad<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Rad<T>;
+
+    fn sub (self, rhs: &Deg<T>) -> Rad<T> {
+        Rad::new (self.value - Rad::from (rhs).value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> SubAssign for Rad<T> where
+    T: Copy + Float + NumCast {
+
+    fn sub_assign (&mut self, rhs: Rad<T>) {
+        self.value = self.value - rhs.value;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> SubAssign<Deg<T>> for Rad<T> where
+    T: Copy + Float + NumCast {
+
+    fn sub_assign (&mut self, rhs: Deg<T>) {
+        self.value = self.value - Rad::from (&rhs).value;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Mul<T> for Rad<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Rad<T>;
+
+    fn mul (self, rhs: T) -> Rad<T> {
+        Rad::new (self.value * rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Mul<T> for &'a Rad<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Rad<T>;
+
+    fn mul (self, rhs: T) -> Rad<T> {
+        Rad::new (self.value * rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> MulAssign<T> for Rad<T> where
+    T: Copy + Float + NumCast {
+
+    fn mul_assign (&mut self, rhs: T) {
+        self.value = self.value * rhs;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Div for Rad<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = T;
+
+    fn div (self, rhs: Rad<T>) -> T {
+        self.value / rhs.value
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Div<&'a Rad<T>> for Rad<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = T;
+
+    fn div (self, rhs: &Rad<T>) -> T {
+        self.value / rhs.value
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Div<Rad<T>> for &'a Rad<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = T;
+
+    fn div (self, rhs: Rad<T>) -> T {
+        self.value / rhs.value
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> Div<&'a Rad<T>> for &'b Rad<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = T;
+
+    fn div (self, rhs: &Rad<T>) -> T {
+        self.value / rhs.value
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Div<T> for Rad<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Rad<T>;
+
+    fn div (self, rhs: T) -> Rad<T> {
+        Rad::new (self.value / rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Div<T> for &'a Rad<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Rad<T>;
+
+    fn div (self, rhs: T) -> Rad<T> {
+        Rad::new (self.value / rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> DivAssign<T> for Rad<T> where
+    T: Copy + Float + NumCast {
+
+    fn div_assign (&mut self, rhs: T) {
+        self.value = self.value / rhs;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Neg for Rad<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Rad<T>;
+
+    fn neg (self) -> Rad<T> {
+        Rad::new (-self.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Neg for &'a Rad<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Rad<T>;
+
+    fn neg (self) -> Rad<T> {
+        Rad::new (-self.value)
+    }
+}
+
+/*===============================================================================================*/
+/*------TRAIT IMPLEMENTATIONS--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Angle for Rad<T> where
+    T: Copy + Float + NumCast {
+
+    type ValType = T;
+
+    fn full_turn () -> Rad<T> {
+        Rad {value: T::from (consts::PI).unwrap () * (T::one () + T::one ())}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn half_turn () -> Rad<T> {
+        Rad {value: T::from (consts::PI).unwrap ()}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn to_rad (&self) -> Rad<T> {
+        Rad {value: self.value}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn from_rad (rad: &Rad<T>) -> Rad<T> {
+        Rad {value: rad.value}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> ApproxEq for Rad<T> where
+    T: ApproxEq + Copy + Float + NumCast {
+
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon () -> T::Epsilon {
+        T::default_epsilon ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_relative () -> T::Epsilon {
+        T::default_max_relative ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_ulps () -> u32 {
+        T::default_max_ulps ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn abs_diff_eq (&self, other: &Rad<T>, epsilon: T::Epsilon) -> bool {
+        self.value.abs_diff_eq (&other.value, epsilon)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn relative_eq (&self, other: &Rad<T>, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        self.value.relative_eq (&other.value, epsilon, max_relative)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn ulps_eq (&self, other: &Rad<T>, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        self.value.ulps_eq (&other.value, epsilon, max_ulps)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> fmt::Display for Rad<T> where
+    T: Copy + Float + fmt::Display + NumCast {
+
+    /// Formats the value of the angle followed by its unit, honouring the precision flag.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Rad;
+    /// let angle = Rad::<f32>::new (0.785);
+    ///
+    /// assert_eq! (format! ("{}", angle), "0.785rad");
+    /// ```
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write! (f, "{}rad", parse::format_component (f, &self.value))
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> FromStr for Rad<T> where
+    T: Copy + Float + FromStr + NumCast {
+
+    type Err = ParseError;
+
+    /// Parses an angle from a number followed by an optional unit, converting it if needed.
+    ///
+    /// See the `parse` module for the accepted units.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Rad;
+    /// let angle: Rad<f64> = "1.2rad".parse ().unwrap ();
+    /// assert_eq! (angle, Rad::new (1.2));
+    ///
+    /// let angle: Rad<f64> = "180°".parse ().unwrap ();
+    /// assert_eq! (angle, Rad::<f64>::PI);
+    ///
+    /// // Without a unit the value is in radians
+    /// let angle: Rad<f64> = "0.5".parse ().unwrap ();
+    /// assert_eq! (angle, Rad::new (0.5));
+    /// ```
+    fn from_str (s: &str) -> Result<Rad<T>, ParseError> {
+        parse::parse_angle (s, |value| Rad {value})
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Sum for Rad<T> where
+    T: Copy + Float + NumCast {
+
+    /// Adds up the angles of an iterator.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Rad;
+    /// let angles = [Rad::<f32>::new (0.5), Rad::new (1.25), Rad::new (-0.25)];
+    ///
+    /// assert_eq! (angles.iter ().sum::<Rad<f32>> (), Rad::new (1.5));
+    /// assert_eq! (angles.iter ().cloned ().sum::<Rad<f32>> (), Rad::new (1.5));
+    /// ```
+    fn sum<I> (iter: I) -> Rad<T> where
+        I: Iterator<Item = Rad<T>> {
+
+        iter.fold (Rad::new (0), |total, angle| total + angle)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Sum<&'a Rad<T>> for Rad<T> where
+    T: Copy + Float + NumCast {
+
+    fn sum<I> (iter: I) -> Rad<T> where
+        I: Iterator<Item = &'a Rad<T>> {
+
+        iter.fold (Rad::new (0), |total, angle| total + angle)
+    }
+}
+
+/*===============================================================================================*/
+/*------PUBLIC METHODS---------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Rad<T> where
+    T: Copy + Float + NumCast {
+
+    /// Returns the sine of the angle.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Rad;
+    /// assert_eq! (Rad::<f32>::new (0).sin (), 0.0);
+    /// ```
+    pub fn sin (&self) -> T {
+        self.value.sin ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the cosine of the angle.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Rad;
+    /// assert_eq! (Rad::<f32>::new (0).cos (), 1.0);
+    /// ```
+    pub fn cos (&self) -> T {
+        self.value.cos ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the tangent of the angle.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Rad;
+    /// assert_eq! (Rad::<f32>::new (0).tan (), 0.0);
+    /// ```
+    pub fn tan (&self) -> T {
+        self.value.tan ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the sine and cosine of the angle, computed together.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Rad;
+    /// let (sin, cos) = Rad::<f64>::new (std::f64::consts::FRAC_PI_2).sin_cos ();
+    ///
+    /// assert! ((sin - 1.0).abs () < 1e-9 && cos.abs () < 1e-9);
+    /// ```
+    pub fn sin_cos (&self) -> (T, T) {
+        self.value.sin_cos ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Wraps the angle to the range [0, 2π).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Rad;
+    /// # fn main () {
+    /// assert_approx_eq! (Rad::<f64>::new (-1.0).normalize (), Rad::new (std::f64::consts::TAU - 1.0));
+    /// # }
+    /// ```
+    pub fn normalize (&self) -> Rad<T> {
+
+        let full_turn = Rad::full_turn ().value;
+        let mut value = self.value % full_turn;
+
+        if value < T::zero () {
+            value = value + full_turn;
+        }
+
+        // Adding a full turn to a tiny negative value can round up to a full turn
+        if value >= full_turn {
+            value = T::zero ();
+        }
+
+        Rad {value}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Wraps the angle to the range (-π, π].
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Rad;
+    /// # fn main () {
+    /// assert_approx_eq! (Rad::<f64>::new (4.0).normalize_signed (), Rad::new (4.0 - std::f64::consts::TAU));
+    /// # }
+    /// ```
+    pub fn normalize_signed (&self) -> Rad<T> {
+
+        let angle = self.normalize ();
+
+        if angle > Rad::half_turn () {
+            angle - Rad::full_turn ()
+        } else {
+            angle
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the signed difference to another angle, taking the shortest way around.
+    ///
+    /// The result is in the range (-π, π].
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Rad;
+    /// # fn main () {
+    /// let delta = Rad::<f64>::new (3.0).shortest_delta (&Rad::new (-3.0));
+    /// assert_approx_eq! (delta.value, std::f64::consts::TAU - 6.0);
+    /// # }
+    /// ```
+    pub fn shortest_delta (&self, to: &Rad<T>) -> Rad<T> {
+        (to - self).normalize_signed ()
+    }
+}
+
+/*===============================================================================================*/
+/*------PUBLIC STATIC METHODS--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Rad<T> where
+    T: Copy + Float + NumCast {
+
+    /// Linearly interpolates between two angles, taking the shortest way around.
+    ///
+    /// The result is not wrapped, so it may lie outside [0, 2π).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Rad;
+    /// # fn main () {
+    /// let angle = Rad::lerp_angle (&Rad::<f64>::new (3.0), &Rad::new (-3.0), 0.5);
+    /// assert_approx_eq! (angle, Rad::new (std::f64::consts::PI));
+    /// # }
+    /// ```
+    pub fn lerp_angle (from: &Rad<T>, to: &Rad<T>, percentage: T) -> Rad<T> {
+        from + from.shortest_delta (to) * percentage
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Moves an angle towards a target by at most `max_delta`, taking the shortest path.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Rad;
+    /// let current = Rad::<f64>::new (3.0);
+    /// let target = Rad::<f64>::new (-3.0);
+    ///
+    /// // Moves forwards across the wrap-around point instead of backwards
+    /// let angle = Rad::move_towards (&current, &target, &Rad::new (0.1));
+    /// assert! (angle.value > current.value);
+    /// ```
+    pub fn move_towards (current: &Rad<T>, target: &Rad<T>, max_delta: &Rad<T>) -> Rad<T> {
+
+        let target = current.value + current.shortest_delta (target).value;
+        Rad {value: util::move_towards (current.value, target, max_delta.value)}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Smoothly moves an angle towards a target using a critically damped spring, <br>
+    /// taking the shortest path.
+    ///
+    /// `velocity` is measured in radians per second. See `util::smooth_damp` for details.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Rad;
+    /// let mut angle = Rad::<f32>::new (3.0);
+    /// let mut velocity = Rad::new (0.0);
+    ///
+    /// angle = Rad::smooth_damp (&angle, &Rad::new (-3.0), &mut velocity, 0.2, 1.0 / 60.0);
+    /// ```
+    pub fn smooth_damp (current: &Rad<T>, target: &Rad<T>, velocity: &mut Rad<T>,
+                        smooth_time: T, delta_time: T) -> Rad<T> {
+
+        let target = current.value + current.shortest_delta (target).value;
+        Rad {value: util::smooth_damp (current.value, target, &mut velocity.value,
+                                       smooth_time, delta_time)}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Moves an angle towards a target with frame-rate independent exponential decay, <br>
+    /// taking the shortest path.
+    ///
+    /// See `util::damp` for details.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Rad;
+    /// let angle = Rad::<f32>::new (3.0);
+    /// let angle = Rad::damp (&angle, &Rad::new (-3.0), 5.0, 1.0 / 60.0);
+    /// ```
+    pub fn damp (current: &Rad<T>, target: &Rad<T>, rate: T, delta_time: T) -> Rad<T> {
+
+        let target = current.value + current.shortest_delta (target).value;
+        Rad {value: util::damp (current.value, target, rate, delta_time)}
     }
 }
@@ -0,0 +1,535 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+// Crate imports
+extern crate num_traits;
+
+// Module imports
+use self::num_traits::{Float, Num, NumCast};
+
+use ::angle::{Angle, Deg, Gradians, Rad};
+use ::approx::ApproxEq;
+use ::parse::{self, ParseError};
+
+use std::convert::From;
+use std::f64::consts;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/*===============================================================================================*/
+/*------TURNS STRUCT-----------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Stores a value in turns.
+#[cfg_attr (feature = "serde_serialize", derive (Deserialize, Serialize))]
+#[derive (Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct Turns<T> where
+    T: Copy + Float + NumCast {
+
+    // Public
+    /// The value of the turn.
+    pub value: T,
+}
+
+/*===============================================================================================*/
+/*------CONSTRUCTORS-----------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Turns<T> where
+    T: Copy + Float + NumCast {
+
+    /// Returns a new `Turns` instance.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Turns;
+    /// let angle = Turns::<f32>::new (0.25);
+    /// ```
+    pub fn new<C> (value: C) -> Turns<T> where
+        C: Num + NumCast {
+
+        Turns {value: T::from (value).unwrap ()}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> From<Rad<T>> for Turns<T> where
+    T: Copy + Float + NumCast {
+
+    fn from (rad: Rad<T>) -> Turns<T> {
+        Turns {value: rad.value / T::from (consts::TAU).unwrap ()}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> From<&'a Rad<T>> for Turns<T> where
+    T: Copy + Float + NumCast {
+
+    fn from (rad: &Rad<T>) -> Turns<T> {
+        Turns {value: rad.value / T::from (consts::TAU).unwrap ()}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> From<Deg<T>> for Turns<T> where
+    T: Copy + Float + NumCast {
+
+    fn from (deg: Deg<T>) -> Turns<T> {
+        Turns {value: deg.value / T::from (360.0).unwrap ()}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> From<&'a Deg<T>> for Turns<T> where
+    T: Copy + Float + NumCast {
+
+    fn from (deg: &Deg<T>) -> Turns<T> {
+        Turns {value: deg.value / T::from (360.0).unwrap ()}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> From<Gradians<T>> for Turns<T> where
+    T: Copy + Float + NumCast {
+
+    fn from (gradians: Gradians<T>) -> Turns<T> {
+        Turns {value: gradians.value / T::from (400.0).unwrap ()}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> From<&'a Gradians<T>> for Turns<T> where
+    T: Copy + Float + NumCast {
+
+    fn from (gradians: &Gradians<T>) -> Turns<T> {
+        Turns {value: gradians.value / T::from (400.0).unwrap ()}
+    }
+}
+
+/*===============================================================================================*/
+/*------OPERATORS--------------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Add for Turns<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Turns<T>;
+
+    fn add (self, rhs: Turns<T>) -> Turns<T> {
+        Turns::new (self.value + rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Add<&'a Turns<T>> for Turns<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Turns<T>;
+
+    fn add (self, rhs: &Turns<T>) -> Turns<T> {
+        Turns::new (self.value + rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Add<Turns<T>> for &'a Turns<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Turns<T>;
+
+    fn add (self, rhs: Turns<T>) -> Turns<T> {
+        Turns::new (self.value + rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> Add<&'a Turns<T>> for &'b Turns<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Turns<T>;
+
+    fn add (self, rhs: &Turns<T>) -> Turns<T> {
+        Turns::new (self.value + rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> AddAssign for Turns<T> where
+    T: Copy + Float + NumCast {
+
+    fn add_assign (&mut self, rhs: Turns<T>) {
+        self.value = self.value + rhs.value;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Sub for Turns<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Turns<T>;
+
+    fn sub (self, rhs: Turns<T>) -> Turns<T> {
+        Turns::new (self.value - rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Sub<&'a Turns<T>> for Turns<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Turns<T>;
+
+    fn sub (self, rhs: &Turns<T>) -> Turns<T> {
+        Turns::new (self.value - rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Sub<Turns<T>> for &'a Turns<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Turns<T>;
+
+    fn sub (self, rhs: Turns<T>) -> Turns<T> {
+        Turns::new (self.value - rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> Sub<&'a Turns<T>> for &'b Turns<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Turns<T>;
+
+    fn sub (self, rhs: &Turns<T>) -> Turns<T> {
+        Turns::new (self.value - rhs.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> SubAssign for Turns<T> where
+    T: Copy + Float + NumCast {
+
+    fn sub_assign (&mut self, rhs: Turns<T>) {
+        self.value = self.value - rhs.value;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Mul<T> for Turns<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Turns<T>;
+
+    fn mul (self, rhs: T) -> Turns<T> {
+        Turns::new (self.value * rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Mul<T> for &'a Turns<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Turns<T>;
+
+    fn mul (self, rhs: T) -> Turns<T> {
+        Turns::new (self.value * rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> MulAssign<T> for Turns<T> where
+    T: Copy + Float + NumCast {
+
+    fn mul_assign (&mut self, rhs: T) {
+        self.value = self.value * rhs;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Div for Turns<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = T;
+
+    fn div (self, rhs: Turns<T>) -> T {
+        self.value / rhs.value
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Div<&'a Turns<T>> for Turns<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = T;
+
+    fn div (self, rhs: &Turns<T>) -> T {
+        self.value / rhs.value
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Div<Turns<T>> for &'a Turns<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = T;
+
+    fn div (self, rhs: Turns<T>) -> T {
+        self.value / rhs.value
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> Div<&'a Turns<T>> for &'b Turns<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = T;
+
+    fn div (self, rhs: &Turns<T>) -> T {
+        self.value / rhs.value
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Div<T> for Turns<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Turns<T>;
+
+    fn div (self, rhs: T) -> Turns<T> {
+        Turns::new (self.value / rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Div<T> for &'a Turns<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Turns<T>;
+
+    fn div (self, rhs: T) -> Turns<T> {
+        Turns::new (self.value / rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> DivAssign<T> for Turns<T> where
+    T: Copy + Float + NumCast {
+
+    fn div_assign (&mut self, rhs: T) {
+        self.value = self.value / rhs;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Neg for Turns<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Turns<T>;
+
+    fn neg (self) -> Turns<T> {
+        Turns::new (-self.value)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Neg for &'a Turns<T> where
+    T: Copy + Float + NumCast {
+
+    type Output = Turns<T>;
+
+    fn neg (self) -> Turns<T> {
+        Turns::new (-self.value)
+    }
+}
+
+/*===============================================================================================*/
+/*------TRAIT IMPLEMENTATIONS--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Angle for Turns<T> where
+    T: Copy + Float + NumCast {
+
+    type ValType = T;
+
+    fn full_turn () -> Turns<T> {
+        Turns {value: T::one ()}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn half_turn () -> Turns<T> {
+        Turns {value: T::from (0.5).unwrap ()}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn to_rad (&self) -> Rad<T> {
+        Rad::from (self)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn from_rad (rad: &Rad<T>) -> Turns<T> {
+        Turns::from (rad)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> ApproxEq for Turns<T> where
+    T: ApproxEq + Copy + Float + NumCast {
+
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon () -> T::Epsilon {
+        T::default_epsilon ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_relative () -> T::Epsilon {
+        T::default_max_relative ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_ulps () -> u32 {
+        T::default_max_ulps ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn abs_diff_eq (&self, other: &Turns<T>, epsilon: T::Epsilon) -> bool {
+        self.value.abs_diff_eq (&other.value, epsilon)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn relative_eq (&self, other: &Turns<T>, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        self.value.relative_eq (&other.value, epsilon, max_relative)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn ulps_eq (&self, other: &Turns<T>, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        self.value.ulps_eq (&other.value, epsilon, max_ulps)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> fmt::Display for Turns<T> where
+    T: Copy + Float + fmt::Display + NumCast {
+
+    /// Formats the value of the angle followed by its unit, honouring the precision flag.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Turns;
+    /// let angle = Turns::<f32>::new (0.25);
+    ///
+    /// assert_eq! (format! ("{}", angle), "0.25turn");
+    /// ```
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write! (f, "{}turn", parse::format_component (f, &self.value))
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> FromStr for Turns<T> where
+    T: Copy + Float + FromStr + NumCast {
+
+    type Err = ParseError;
+
+    /// Parses an angle from a number followed by an optional unit, converting it if needed.
+    ///
+    /// See the `parse` module for the accepted units.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Turns;
+    /// let angle: Turns<f64> = "90°".parse ().unwrap ();
+    /// assert_eq! (angle, Turns::new (0.25));
+    /// ```
+    fn from_str (s: &str) -> Result<Turns<T>, ParseError> {
+        parse::parse_angle (s, |value| Turns {value})
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Sum for Turns<T> where
+    T: Copy + Float + NumCast {
+
+    /// Adds up the angles of an iterator.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Turns;
+    /// let angles = [Turns::<f32>::new (0.5), Turns::new (0.25), Turns::new (-0.125)];
+    ///
+    /// assert_eq! (angles.iter ().sum::<Turns<f32>> (), Turns::new (0.625));
+    /// assert_eq! (angles.iter ().cloned ().sum::<Turns<f32>> (), Turns::new (0.625));
+    /// ```
+    fn sum<I> (iter: I) -> Turns<T> where
+        I: Iterator<Item = Turns<T>> {
+
+        iter.fold (Turns::new (0), |total, angle| total + angle)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Sum<&'a Turns<T>> for Turns<T> where
+    T: Copy + Float + NumCast {
+
+    fn sum<I> (iter: I) -> Turns<T> where
+        I: Iterator<Item = &'a Turns<T>> {
+
+        iter.fold (Turns::new (0), |total, angle| total + angle)
+    }
+}
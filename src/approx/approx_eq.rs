@@ -0,0 +1,213 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+// Module imports
+use std::{f32, f64};
+
+/*===============================================================================================*/
+/*------APPROX EQ TRAIT--------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Implemented by all types that can be compared approximately.
+pub trait ApproxEq {
+
+    /// The type used for tolerances.
+    type Epsilon: Copy;
+
+    /// Returns the default absolute tolerance.
+    fn default_epsilon () -> Self::Epsilon;
+    /// Returns the default relative tolerance.
+    fn default_max_relative () -> Self::Epsilon;
+    /// Returns the default number of units in the last place.
+    fn default_max_ulps () -> u32;
+
+    /// Returns true if the absolute difference is within `epsilon`.
+    fn abs_diff_eq (&self, other: &Self, epsilon: Self::Epsilon) -> bool;
+    /// Returns true if the difference is within `epsilon`, or within `max_relative`
+    /// of the largest magnitude.
+    fn relative_eq (&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool;
+    /// Returns true if the difference is within `epsilon`, or the values are at most
+    /// `max_ulps` representable floats apart. NaN is never equal to anything.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::approx::ApproxEq;
+    /// assert! (1.0_f32.ulps_eq (&1.0000001, 0.0, 4));
+    /// assert! (!::std::f32::NAN.ulps_eq (&::std::f32::NAN, 0.0, 4));
+    /// ```
+    fn ulps_eq (&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool;
+
+    /// Returns true if two values are relatively equal using the default tolerances.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::approx::ApproxEq;
+    /// assert! ((0.1_f32 + 0.2).approx_eq (&0.3));
+    /// ```
+    fn approx_eq (&self, other: &Self) -> bool {
+        self.relative_eq (other, Self::default_epsilon (), Self::default_max_relative ())
+    }
+}
+
+/*===============================================================================================*/
+/*------TRAIT IMPLEMENTATIONS--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl ApproxEq for f32 {
+
+    type Epsilon = f32;
+
+    fn default_epsilon () -> f32 {
+        f32::EPSILON
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_relative () -> f32 {
+        f32::EPSILON
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_ulps () -> u32 {
+        4
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn abs_diff_eq (&self, other: &f32, epsilon: f32) -> bool {
+        (self - other).abs () <= epsilon
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn relative_eq (&self, other: &f32, epsilon: f32, max_relative: f32) -> bool {
+
+        // Handles infinities of the same sign
+        if self == other {
+            return true;
+        }
+
+        if self.is_infinite () || other.is_infinite () {
+            return false;
+        }
+
+        let abs_diff = (self - other).abs ();
+
+        if abs_diff <= epsilon {
+            return true;
+        }
+
+        abs_diff <= self.abs ().max (other.abs ()) * max_relative
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn ulps_eq (&self, other: &f32, epsilon: f32, max_ulps: u32) -> bool {
+
+        // NaN shares its bit pattern with itself, but is never equal
+        if self.is_nan () || other.is_nan () {
+            return false;
+        }
+
+        if self.abs_diff_eq (other, epsilon) {
+            return true;
+        }
+
+        if self.is_sign_positive () != other.is_sign_positive () {
+            return false;
+        }
+
+        let lhs = self.to_bits () as i64;
+        let rhs = other.to_bits () as i64;
+
+        (lhs - rhs).abs () <= max_ulps as i64
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl ApproxEq for f64 {
+
+    type Epsilon = f64;
+
+    fn default_epsilon () -> f64 {
+        f64::EPSILON
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_relative () -> f64 {
+        f64::EPSILON
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_ulps () -> u32 {
+        4
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn abs_diff_eq (&self, other: &f64, epsilon: f64) -> bool {
+        (self - other).abs () <= epsilon
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn relative_eq (&self, other: &f64, epsilon: f64, max_relative: f64) -> bool {
+
+        // Handles infinities of the same sign
+        if self == other {
+            return true;
+        }
+
+        if self.is_infinite () || other.is_infinite () {
+            return false;
+        }
+
+        let abs_diff = (self - other).abs ();
+
+        if abs_diff <= epsilon {
+            return true;
+        }
+
+        abs_diff <= self.abs ().max (other.abs ()) * max_relative
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn ulps_eq (&self, other: &f64, epsilon: f64, max_ulps: u32) -> bool {
+
+        // NaN shares its bit pattern with itself, but is never equal
+        if self.is_nan () || other.is_nan () {
+            return false;
+        }
+
+        if self.abs_diff_eq (other, epsilon) {
+            return true;
+        }
+
+        if self.is_sign_positive () != other.is_sign_positive () {
+            return false;
+        }
+
+        let lhs = self.to_bits () as i64;
+        let rhs = other.to_bits () as i64;
+
+        (lhs - rhs).abs () <= max_ulps as i64
+    }
+}
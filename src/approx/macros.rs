@@ -0,0 +1,187 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+/*===============================================================================================*/
+/*------PUBLIC MACROS----------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Asserts that two values are approximately equal using the default tolerances.
+///
+/// # Examples
+/// ```
+/// # #[macro_use] extern crate ion_math;
+/// # use ion_math::vector::Vec3;
+/// # fn main () {
+/// let vec = Vec3::<f32>::new (0.1, 0.2, 0.3) * 3.0;
+/// assert_approx_eq! (vec, Vec3::new (0.3, 0.6, 0.9));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_approx_eq {
+
+    ($lhs:expr, $rhs:expr) => {{
+
+        let (lhs, rhs) = (&$lhs, &$rhs);
+
+        if !$crate::approx::ApproxEq::approx_eq (lhs, rhs) {
+            panic! ("assertion failed: `(left ≈ right)`\n  left: `{:?}`,\n right: `{:?}`", lhs, rhs);
+        }
+    }};
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+/// Asserts that two values are not approximately equal using the default tolerances.
+///
+/// # Examples
+/// ```
+/// # #[macro_use] extern crate ion_math;
+/// # use ion_math::angle::Deg;
+/// # fn main () {
+/// assert_approx_ne! (Deg::<f32>::new (45.0), Deg::new (45.1));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_approx_ne {
+
+    ($lhs:expr, $rhs:expr) => {{
+
+        let (lhs, rhs) = (&$lhs, &$rhs);
+
+        if $crate::approx::ApproxEq::approx_eq (lhs, rhs) {
+            panic! ("assertion failed: `(left !≈ right)`\n  left: `{:?}`,\n right: `{:?}`", lhs, rhs);
+        }
+    }};
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+/// Asserts that the absolute difference between two values is within an epsilon.
+///
+/// # Examples
+/// ```
+/// # #[macro_use] extern crate ion_math;
+/// # use ion_math::vector::Vec2;
+/// # fn main () {
+/// assert_abs_diff_eq! (Vec2::<f32>::new (1.0, 2.0), Vec2::new (1.001, 2.0), epsilon = 0.01);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_abs_diff_eq {
+
+    ($lhs:expr, $rhs:expr) => {{
+
+        fn default_epsilon<T: $crate::approx::ApproxEq> (_: &T) -> T::Epsilon {
+            T::default_epsilon ()
+        }
+
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        let epsilon = default_epsilon (lhs);
+
+        $crate::assert_abs_diff_eq! (*lhs, *rhs, epsilon = epsilon);
+    }};
+
+    ($lhs:expr, $rhs:expr, epsilon = $epsilon:expr) => {{
+
+        let (lhs, rhs, epsilon) = (&$lhs, &$rhs, $epsilon);
+
+        if !$crate::approx::ApproxEq::abs_diff_eq (lhs, rhs, epsilon) {
+
+            panic! ("assertion failed: `abs_diff_eq (left, right, epsilon = {:?})`\n  left: `{:?}`,\n right: `{:?}`",
+                    epsilon, lhs, rhs);
+        }
+    }};
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+/// Asserts that two values are relatively equal.
+///
+/// # Examples
+/// ```
+/// # #[macro_use] extern crate ion_math;
+/// # use ion_math::colour::RGBA;
+/// # fn main () {
+/// assert_relative_eq! (RGBA::new (0.5, 0.5, 0.5, 1.0) * 0.1, RGBA::new (0.05, 0.05, 0.05, 0.1));
+/// assert_relative_eq! (1000.0_f32, 1000.1, epsilon = 0.0, max_relative = 0.001);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_relative_eq {
+
+    ($lhs:expr, $rhs:expr) => {{
+
+        fn defaults<T: $crate::approx::ApproxEq> (_: &T) -> (T::Epsilon, T::Epsilon) {
+            (T::default_epsilon (), T::default_max_relative ())
+        }
+
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        let (epsilon, max_relative) = defaults (lhs);
+
+        $crate::assert_relative_eq! (*lhs, *rhs, epsilon = epsilon, max_relative = max_relative);
+    }};
+
+    ($lhs:expr, $rhs:expr, epsilon = $epsilon:expr, max_relative = $max_relative:expr) => {{
+
+        let (lhs, rhs, epsilon, max_relative) = (&$lhs, &$rhs, $epsilon, $max_relative);
+
+        if !$crate::approx::ApproxEq::relative_eq (lhs, rhs, epsilon, max_relative) {
+
+            panic! ("assertion failed: `relative_eq (left, right, epsilon = {:?}, max_relative = {:?})`\n  left: `{:?}`,\n right: `{:?}`",
+                    epsilon, max_relative, lhs, rhs);
+        }
+    }};
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+/// Asserts that two values are within a number of units in the last place.
+///
+/// # Examples
+/// ```
+/// # #[macro_use] extern crate ion_math;
+/// # use ion_math::matrix::{Mat3, MatTrait};
+/// # fn main () {
+/// assert_ulps_eq! (Mat3::<f32>::identity () * Mat3::identity (), Mat3::identity ());
+/// assert_ulps_eq! (1.0_f64, 1.0 + 2e-16, epsilon = 0.0, max_ulps = 1);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_ulps_eq {
+
+    ($lhs:expr, $rhs:expr) => {{
+
+        fn defaults<T: $crate::approx::ApproxEq> (_: &T) -> (T::Epsilon, u32) {
+            (T::default_epsilon (), T::default_max_ulps ())
+        }
+
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        let (epsilon, max_ulps) = defaults (lhs);
+
+        $crate::assert_ulps_eq! (*lhs, *rhs, epsilon = epsilon, max_ulps = max_ulps);
+    }};
+
+    ($lhs:expr, $rhs:expr, epsilon = $epsilon:expr, max_ulps = $max_ulps:expr) => {{
+
+        let (lhs, rhs, epsilon, max_ulps) = (&$lhs, &$rhs, $epsilon, $max_ulps);
+
+        if !$crate::approx::ApproxEq::ulps_eq (lhs, rhs, epsilon, max_ulps) {
+
+            panic! ("assertion failed: `ulps_eq (left, right, epsilon = {:?}, max_ulps = {:?})`\n  left: `{:?}`,\n right: `{:?}`",
+                    epsilon, max_ulps, lhs, rhs);
+        }
+    }};
+}
@@ -0,0 +1,29 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+/*===============================================================================================*/
+//! Approximate equality for floating point types.
+//!
+//! Values can be compared using an absolute epsilon, a relative tolerance or <br>
+//! units in the last place (ULPs). The `assert_*` macros wrap these comparisons for tests.
+/*===============================================================================================*/
+
+// Modules
+mod approx_eq;
+mod macros;
+
+// Module imports
+pub use self::approx_eq::ApproxEq;
@@ -0,0 +1,448 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+// Module imports
+use ::angle::Deg;
+use ::approx::ApproxEq;
+use ::colour::{ColourTrait, HSVA, RGBA};
+use ::parse::{self, ParseError};
+use ::util;
+
+use std::convert::From;
+use std::fmt;
+use std::str::FromStr;
+
+/*===============================================================================================*/
+/*------HSLA STRUCT------------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Stores a HSL colour value.
+///
+/// Converting to and from `RGBA` is lossless, apart from the hue of grey colours, <br>
+/// which is undefined and set to 0°.
+#[cfg_attr (feature = "serde_serialize", derive (Deserialize, Serialize))]
+#[derive (Copy, Clone, Debug, PartialEq)]
+pub struct HSLA {
+
+    // Public
+    /// Hue, in the range [0°, 360°).
+    pub h: Deg<f32>,
+    /// Saturation.
+    pub s: f32,
+    /// Lightness.
+    pub l: f32,
+    /// Alpha channel.
+    pub a: f32,
+}
+
+/*===============================================================================================*/
+/*------CONSTRUCTORS-----------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl HSLA {
+
+    /// Returns a new `HSLA` instance.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Deg;
+    /// # use ion_math::colour::HSLA;
+    /// let colour = HSLA::new (Deg::new (120), 1.0, 0.5, 1.0);
+    /// ```
+    pub fn new (h: Deg<f32>, s: f32, l: f32, a: f32) -> HSLA {
+        HSLA {h, s, l, a}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a> From<&'a RGBA> for HSLA {
+
+    /// Converts a `RGBA` colour to HSL.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Deg;
+    /// # use ion_math::colour::{ColourTrait, HSLA, RGBA};
+    /// # fn main () {
+    /// let colour = HSLA::from (&RGBA::new (0.0, 0.5, 1.0, 1.0));
+    /// assert_approx_eq! (colour, HSLA::new (Deg::new (210), 1.0, 0.5, 1.0));
+    ///
+    /// let rgba = RGBA::new (0.2, 0.7, 0.4, 0.5);
+    /// assert_approx_eq! (RGBA::from (&HSLA::from (&rgba)), rgba);
+    /// # }
+    /// ```
+    fn from (rgba: &RGBA) -> HSLA {
+        HSLA::from (&HSVA::from (rgba))
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a> From<&'a HSVA> for HSLA {
+
+    /// Converts a `HSVA` colour to HSL.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Deg;
+    /// # use ion_math::colour::{HSLA, HSVA};
+    /// # fn main () {
+    /// let colour = HSLA::from (&HSVA::new (Deg::new (120), 1.0, 1.0, 1.0));
+    /// assert_approx_eq! (colour, HSLA::new (Deg::new (120), 1.0, 0.5, 1.0));
+    ///
+    /// let hsva = HSVA::new (Deg::new (210), 0.6, 0.4, 0.5);
+    /// assert_approx_eq! (HSVA::from (&HSLA::from (&hsva)), hsva);
+    /// # }
+    /// ```
+    fn from (colour: &HSVA) -> HSLA {
+
+        let l = colour.v * (1.0 - colour.s * 0.5);
+        let range = l.min (1.0 - l);
+
+        HSLA {h: colour.h,
+              s: if range == 0.0 {0.0} else {(colour.v - l) / range},
+              l,
+              a: colour.a}
+    }
+}
+
+/*===============================================================================================*/
+/*------TRAIT IMPLEMENTATIONS--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl ColourTrait for HSLA {
+
+    /// Returns the colour black.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::{ColourTrait, HSLA};
+    /// let colour = HSLA::black ();
+    /// ```
+    fn black () -> HSLA {
+        HSLA::new (Deg::new (0), 0.0, 0.0, 1.0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the colour light grey.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::{ColourTrait, HSLA};
+    /// let colour = HSLA::light_grey ();
+    /// ```
+    fn light_grey () -> HSLA {
+        HSLA::new (Deg::new (0), 0.0, 0.75, 1.0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the colour grey.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::{ColourTrait, HSLA};
+    /// let colour = HSLA::grey ();
+    /// ```
+    fn grey () -> HSLA {
+        HSLA::new (Deg::new (0), 0.0, 0.5, 1.0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the colour dark grey.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::{ColourTrait, HSLA};
+    /// let colour = HSLA::dark_grey ();
+    /// ```
+    fn dark_grey () -> HSLA {
+        HSLA::new (Deg::new (0), 0.0, 0.25, 1.0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the colour white.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::{ColourTrait, HSLA};
+    /// let colour = HSLA::white ();
+    /// ```
+    fn white () -> HSLA {
+        HSLA::new (Deg::new (0), 0.0, 1.0, 1.0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the colour red.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::{ColourTrait, HSLA};
+    /// let colour = HSLA::red ();
+    /// ```
+    fn red () -> HSLA {
+        HSLA::new (Deg::new (0), 1.0, 0.5, 1.0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the colour green.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::{ColourTrait, HSLA};
+    /// let colour = HSLA::green ();
+    /// ```
+    fn green () -> HSLA {
+        HSLA::new (Deg::new (120), 1.0, 0.5, 1.0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the colour blue.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::{ColourTrait, HSLA};
+    /// let colour = HSLA::blue ();
+    /// ```
+    fn blue () -> HSLA {
+        HSLA::new (Deg::new (240), 1.0, 0.5, 1.0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the colour yellow.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::{ColourTrait, HSLA};
+    /// let colour = HSLA::yellow ();
+    /// ```
+    fn yellow () -> HSLA {
+        HSLA::new (Deg::new (60), 1.0, 0.5, 1.0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the colour cyan.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::{ColourTrait, HSLA};
+    /// let colour = HSLA::cyan ();
+    /// ```
+    fn cyan () -> HSLA {
+        HSLA::new (Deg::new (180), 1.0, 0.5, 1.0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the colour magenta.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::{ColourTrait, HSLA};
+    /// let colour = HSLA::magenta ();
+    /// ```
+    fn magenta () -> HSLA {
+        HSLA::new (Deg::new (300), 1.0, 0.5, 1.0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a fully transparent colour.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::{ColourTrait, HSLA};
+    /// let colour = HSLA::transparent ();
+    /// ```
+    fn transparent () -> HSLA {
+        HSLA::new (Deg::new (0), 0.0, 0.0, 0.0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Lerps between two colours, taking the shorter way around the hue circle.
+    ///
+    /// When one colour is grey, the hue of the other colour is kept.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Deg;
+    /// # use ion_math::colour::{ColourTrait, HSLA};
+    /// # fn main () {
+    /// let start = HSLA::new (Deg::new (350), 1.0, 0.5, 1.0);
+    /// let end = HSLA::new (Deg::new (30), 1.0, 0.5, 1.0);
+    ///
+    /// // Passes through red at 10°, rather than through green and blue
+    /// let colour = HSLA::lerp (&start, &end, 0.5);
+    /// assert_approx_eq! (colour.h, Deg::new (10.0));
+    ///
+    /// // A grey end takes the hue of the coloured end
+    /// let grey = HSLA::new (Deg::new (0), 0.0, 0.5, 1.0);
+    /// let green = HSLA::new (Deg::new (120), 1.0, 0.5, 1.0);
+    ///
+    /// assert_approx_eq! (HSLA::lerp (&grey, &green, 0.0).h, Deg::new (120.0));
+    /// assert_approx_eq! (HSLA::lerp (&grey, &green, 1.0).h, Deg::new (120.0));
+    ///
+    /// // Between two greys the hue stays put
+    /// let dark_grey = HSLA::new (Deg::new (200), 0.0, 0.2, 1.0);
+    ///
+    /// assert_approx_eq! (HSLA::lerp (&grey, &dark_grey, 0.0).h, Deg::new (200.0));
+    /// assert_approx_eq! (HSLA::lerp (&grey, &dark_grey, 1.0).h, Deg::new (200.0));
+    /// # }
+    /// ```
+    fn lerp (start: &HSLA, end: &HSLA, percentage: f32) -> HSLA {
+
+        let percentage = util::clamp (percentage, 0.0, 1.0);
+
+        // Grey colours have no meaningful hue to interpolate from
+        let start_hue = if start.s == 0.0 {end.h} else {start.h};
+        let end_hue = if end.s == 0.0 {start_hue} else {end.h};
+
+        HSLA {h: Deg::lerp_angle (&start_hue, &end_hue, percentage).normalize (),
+              s: start.s + (end.s - start.s) * percentage,
+              l: start.l + (end.l - start.l) * percentage,
+              a: start.a + (end.a - start.a) * percentage}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Clamps each channel between the channels of `min` and `max`.
+    ///
+    /// The hue is clamped linearly on its value in degrees, not around the hue circle, <br>
+    /// so a range that wraps past 0°, such as 330° to 30°, cannot be expressed.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Deg;
+    /// # use ion_math::colour::{ColourTrait, HSLA};
+    /// # fn main () {
+    /// let colour = HSLA::new (Deg::new (300), 1.0, 0.2, 1.0);
+    /// let min = HSLA::new (Deg::new (0), 0.0, 0.5, 0.0);
+    /// let max = HSLA::new (Deg::new (240), 1.0, 1.0, 1.0);
+    ///
+    /// assert_approx_eq! (colour.clamp (&min, &max), HSLA::new (Deg::new (240), 1.0, 0.5, 1.0));
+    /// # }
+    /// ```
+    fn clamp (&self, min: &HSLA, max: &HSLA) -> HSLA {
+
+        HSLA::new (Deg::new (util::clamp (self.h.value, min.h.value, max.h.value)),
+                   util::clamp (self.s, min.s, max.s),
+                   util::clamp (self.l, min.l, max.l),
+                   util::clamp (self.a, min.a, max.a))
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl ApproxEq for HSLA {
+
+    type Epsilon = f32;
+
+    fn default_epsilon () -> f32 {
+        f32::default_epsilon ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_relative () -> f32 {
+        f32::default_max_relative ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_ulps () -> u32 {
+        f32::default_max_ulps ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn abs_diff_eq (&self, other: &HSLA, epsilon: f32) -> bool {
+
+        self.h.abs_diff_eq (&other.h, epsilon) &&
+        self.s.abs_diff_eq (&other.s, epsilon) &&
+        self.l.abs_diff_eq (&other.l, epsilon) &&
+        self.a.abs_diff_eq (&other.a, epsilon)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn relative_eq (&self, other: &HSLA, epsilon: f32, max_relative: f32) -> bool {
+
+        self.h.relative_eq (&other.h, epsilon, max_relative) &&
+        self.s.relative_eq (&other.s, epsilon, max_relative) &&
+        self.l.relative_eq (&other.l, epsilon, max_relative) &&
+        self.a.relative_eq (&other.a, epsilon, max_relative)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn ulps_eq (&self, other: &HSLA, epsilon: f32, max_ulps: u32) -> bool {
+
+        self.h.ulps_eq (&other.h, epsilon, max_ulps) &&
+        self.s.ulps_eq (&other.s, epsilon, max_ulps) &&
+        self.l.ulps_eq (&other.l, epsilon, max_ulps) &&
+        self.a.ulps_eq (&other.a, epsilon, max_ulps)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl fmt::Display for HSLA {
+
+    /// Formats the colour as `(h, s, l, a)` with the hue in degrees, honouring the precision flag.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::{ColourTrait, HSLA};
+    /// assert_eq! (format! ("{:.1}", HSLA::green ()), "(120.0, 1.0, 0.5, 1.0)");
+    /// ```
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        parse::fmt_components (f, &[self.h.value, self.s, self.l, self.a])
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl FromStr for HSLA {
+
+    type Err = ParseError;
+
+    /// Parses a colour from a string such as `(120, 1, 0.5, 1)`, with the hue in degrees.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::HSLA;
+    /// let colour: HSLA = "(120, 1, 0.5, 1)".parse ().unwrap ();
+    /// ```
+    fn from_str (s: &str) -> Result<HSLA, ParseError> {
+
+        let c = parse::parse_components (s, 4)?;
+        Ok (HSLA {h: Deg::new (c[0]), s: c[1], l: c[2], a: c[3]})
+    }
+}
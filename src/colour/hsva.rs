@@ -0,0 +1,466 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+// Module imports
+use ::angle::Deg;
+use ::approx::ApproxEq;
+use ::colour::{ColourTrait, HSLA, RGBA};
+use ::parse::{self, ParseError};
+use ::util;
+
+use std::convert::From;
+use std::fmt;
+use std::str::FromStr;
+
+/*===============================================================================================*/
+/*------HSVA STRUCT------------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Stores a HSV colour value.
+///
+/// Converting to and from `RGBA` is lossless, apart from the hue of grey colours, <br>
+/// which is undefined and set to 0°.
+#[cfg_attr (feature = "serde_serialize", derive (Deserialize, Serialize))]
+#[derive (Copy, Clone, Debug, PartialEq)]
+pub struct HSVA {
+
+    // Public
+    /// Hue, in the range [0°, 360°).
+    pub h: Deg<f32>,
+    /// Saturation.
+    pub s: f32,
+    /// Value.
+    pub v: f32,
+    /// Alpha channel.
+    pub a: f32,
+}
+
+/*===============================================================================================*/
+/*------CONSTRUCTORS-----------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl HSVA {
+
+    /// Returns a new `HSVA` instance.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Deg;
+    /// # use ion_math::colour::HSVA;
+    /// let colour = HSVA::new (Deg::new (120), 1.0, 0.5, 1.0);
+    /// ```
+    pub fn new (h: Deg<f32>, s: f32, v: f32, a: f32) -> HSVA {
+        HSVA {h, s, v, a}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a> From<&'a RGBA> for HSVA {
+
+    /// Converts a `RGBA` colour to HSV.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Deg;
+    /// # use ion_math::colour::{ColourTrait, HSVA, RGBA};
+    /// # fn main () {
+    /// let colour = HSVA::from (&RGBA::new (0.0, 0.5, 1.0, 1.0));
+    /// assert_approx_eq! (colour, HSVA::new (Deg::new (210), 1.0, 1.0, 1.0));
+    ///
+    /// let rgba = RGBA::new (0.2, 0.7, 0.4, 0.5);
+    /// assert_approx_eq! (RGBA::from (&HSVA::from (&rgba)), rgba);
+    /// # }
+    /// ```
+    fn from (rgba: &RGBA) -> HSVA {
+
+        let max = rgba.r.max (rgba.g).max (rgba.b);
+        let min = rgba.r.min (rgba.g).min (rgba.b);
+        let delta = max - min;
+
+        let hue = if delta == 0.0 {
+            0.0
+        } else if max == rgba.r {
+            (rgba.g - rgba.b) / delta
+        } else if max == rgba.g {
+            (rgba.b - rgba.r) / delta + 2.0
+        } else {
+            (rgba.r - rgba.g) / delta + 4.0
+        };
+
+        HSVA {h: Deg::new (hue * 60.0).normalize (),
+              s: if max == 0.0 {0.0} else {delta / max},
+              v: max,
+              a: rgba.a}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a> From<&'a HSLA> for HSVA {
+
+    /// Converts a `HSLA` colour to HSV.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Deg;
+    /// # use ion_math::colour::{HSLA, HSVA};
+    /// # fn main () {
+    /// let colour = HSVA::from (&HSLA::new (Deg::new (120), 1.0, 0.5, 1.0));
+    /// assert_approx_eq! (colour, HSVA::new (Deg::new (120), 1.0, 1.0, 1.0));
+    ///
+    /// let hsla = HSLA::new (Deg::new (210), 0.6, 0.4, 0.5);
+    /// assert_approx_eq! (HSLA::from (&HSVA::from (&hsla)), hsla);
+    /// # }
+    /// ```
+    fn from (colour: &HSLA) -> HSVA {
+
+        let range = colour.l.min (1.0 - colour.l);
+        let v = colour.l + colour.s * range;
+
+        HSVA {h: colour.h,
+              s: if v == 0.0 {0.0} else {2.0 * (1.0 - colour.l / v)},
+              v,
+              a: colour.a}
+    }
+}
+
+/*===============================================================================================*/
+/*------TRAIT IMPLEMENTATIONS--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl ColourTrait for HSVA {
+
+    /// Returns the colour black.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::{ColourTrait, HSVA};
+    /// let colour = HSVA::black ();
+    /// ```
+    fn black () -> HSVA {
+        HSVA::new (Deg::new (0), 0.0, 0.0, 1.0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the colour light grey.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::{ColourTrait, HSVA};
+    /// let colour = HSVA::light_grey ();
+    /// ```
+    fn light_grey () -> HSVA {
+        HSVA::new (Deg::new (0), 0.0, 0.75, 1.0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the colour grey.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::{ColourTrait, HSVA};
+    /// let colour = HSVA::grey ();
+    /// ```
+    fn grey () -> HSVA {
+        HSVA::new (Deg::new (0), 0.0, 0.5, 1.0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the colour dark grey.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::{ColourTrait, HSVA};
+    /// let colour = HSVA::dark_grey ();
+    /// ```
+    fn dark_grey () -> HSVA {
+        HSVA::new (Deg::new (0), 0.0, 0.25, 1.0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the colour white.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::{ColourTrait, HSVA};
+    /// let colour = HSVA::white ();
+    /// ```
+    fn white () -> HSVA {
+        HSVA::new (Deg::new (0), 0.0, 1.0, 1.0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the colour red.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::{ColourTrait, HSVA};
+    /// let colour = HSVA::red ();
+    /// ```
+    fn red () -> HSVA {
+        HSVA::new (Deg::new (0), 1.0, 1.0, 1.0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the colour green.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::{ColourTrait, HSVA};
+    /// let colour = HSVA::green ();
+    /// ```
+    fn green () -> HSVA {
+        HSVA::new (Deg::new (120), 1.0, 1.0, 1.0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the colour blue.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::{ColourTrait, HSVA};
+    /// let colour = HSVA::blue ();
+    /// ```
+    fn blue () -> HSVA {
+        HSVA::new (Deg::new (240), 1.0, 1.0, 1.0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the colour yellow.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::{ColourTrait, HSVA};
+    /// let colour = HSVA::yellow ();
+    /// ```
+    fn yellow () -> HSVA {
+        HSVA::new (Deg::new (60), 1.0, 1.0, 1.0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the colour cyan.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::{ColourTrait, HSVA};
+    /// let colour = HSVA::cyan ();
+    /// ```
+    fn cyan () -> HSVA {
+        HSVA::new (Deg::new (180), 1.0, 1.0, 1.0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the colour magenta.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::{ColourTrait, HSVA};
+    /// let colour = HSVA::magenta ();
+    /// ```
+    fn magenta () -> HSVA {
+        HSVA::new (Deg::new (300), 1.0, 1.0, 1.0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a fully transparent colour.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::{ColourTrait, HSVA};
+    /// let colour = HSVA::transparent ();
+    /// ```
+    fn transparent () -> HSVA {
+        HSVA::new (Deg::new (0), 0.0, 0.0, 0.0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Lerps between two colours, taking the shorter way around the hue circle.
+    ///
+    /// When one colour is grey, the hue of the other colour is kept.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Deg;
+    /// # use ion_math::colour::{ColourTrait, HSVA};
+    /// # fn main () {
+    /// let start = HSVA::new (Deg::new (350), 1.0, 0.5, 1.0);
+    /// let end = HSVA::new (Deg::new (30), 1.0, 0.5, 1.0);
+    ///
+    /// // Passes through red at 10°, rather than through green and blue
+    /// let colour = HSVA::lerp (&start, &end, 0.5);
+    /// assert_approx_eq! (colour.h, Deg::new (10.0));
+    ///
+    /// // A grey end takes the hue of the coloured end
+    /// let grey = HSVA::new (Deg::new (0), 0.0, 0.5, 1.0);
+    /// let green = HSVA::new (Deg::new (120), 1.0, 0.5, 1.0);
+    ///
+    /// assert_approx_eq! (HSVA::lerp (&grey, &green, 0.0).h, Deg::new (120.0));
+    /// assert_approx_eq! (HSVA::lerp (&grey, &green, 1.0).h, Deg::new (120.0));
+    ///
+    /// // Between two greys the hue stays put
+    /// let dark_grey = HSVA::new (Deg::new (200), 0.0, 0.2, 1.0);
+    ///
+    /// assert_approx_eq! (HSVA::lerp (&grey, &dark_grey, 0.0).h, Deg::new (200.0));
+    /// assert_approx_eq! (HSVA::lerp (&grey, &dark_grey, 1.0).h, Deg::new (200.0));
+    /// # }
+    /// ```
+    fn lerp (start: &HSVA, end: &HSVA, percentage: f32) -> HSVA {
+
+        let percentage = util::clamp (percentage, 0.0, 1.0);
+
+        // Grey colours have no meaningful hue to interpolate from
+        let start_hue = if start.s == 0.0 {end.h} else {start.h};
+        let end_hue = if end.s == 0.0 {start_hue} else {end.h};
+
+        HSVA {h: Deg::lerp_angle (&start_hue, &end_hue, percentage).normalize (),
+              s: start.s + (end.s - start.s) * percentage,
+              v: start.v + (end.v - start.v) * percentage,
+              a: start.a + (end.a - start.a) * percentage}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Clamps each channel between the channels of `min` and `max`.
+    ///
+    /// The hue is clamped linearly on its value in degrees, not around the hue circle, <br>
+    /// so a range that wraps past 0°, such as 330° to 30°, cannot be expressed.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Deg;
+    /// # use ion_math::colour::{ColourTrait, HSVA};
+    /// # fn main () {
+    /// let colour = HSVA::new (Deg::new (300), 1.0, 0.2, 1.0);
+    /// let min = HSVA::new (Deg::new (0), 0.0, 0.5, 0.0);
+    /// let max = HSVA::new (Deg::new (240), 1.0, 1.0, 1.0);
+    ///
+    /// assert_approx_eq! (colour.clamp (&min, &max), HSVA::new (Deg::new (240), 1.0, 0.5, 1.0));
+    /// # }
+    /// ```
+    fn clamp (&self, min: &HSVA, max: &HSVA) -> HSVA {
+
+        HSVA::new (Deg::new (util::clamp (self.h.value, min.h.value, max.h.value)),
+                   util::clamp (self.s, min.s, max.s),
+                   util::clamp (self.v, min.v, max.v),
+                   util::clamp (self.a, min.a, max.a))
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl ApproxEq for HSVA {
+
+    type Epsilon = f32;
+
+    fn default_epsilon () -> f32 {
+        f32::default_epsilon ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_relative () -> f32 {
+        f32::default_max_relative ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_ulps () -> u32 {
+        f32::default_max_ulps ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn abs_diff_eq (&self, other: &HSVA, epsilon: f32) -> bool {
+
+        self.h.abs_diff_eq (&other.h, epsilon) &&
+        self.s.abs_diff_eq (&other.s, epsilon) &&
+        self.v.abs_diff_eq (&other.v, epsilon) &&
+        self.a.abs_diff_eq (&other.a, epsilon)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn relative_eq (&self, other: &HSVA, epsilon: f32, max_relative: f32) -> bool {
+
+        self.h.relative_eq (&other.h, epsilon, max_relative) &&
+        self.s.relative_eq (&other.s, epsilon, max_relative) &&
+        self.v.relative_eq (&other.v, epsilon, max_relative) &&
+        self.a.relative_eq (&other.a, epsilon, max_relative)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn ulps_eq (&self, other: &HSVA, epsilon: f32, max_ulps: u32) -> bool {
+
+        self.h.ulps_eq (&other.h, epsilon, max_ulps) &&
+        self.s.ulps_eq (&other.s, epsilon, max_ulps) &&
+        self.v.ulps_eq (&other.v, epsilon, max_ulps) &&
+        self.a.ulps_eq (&other.a, epsilon, max_ulps)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl fmt::Display for HSVA {
+
+    /// Formats the colour as `(h, s, v, a)` with the hue in degrees, honouring the precision flag.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::{ColourTrait, HSVA};
+    /// assert_eq! (format! ("{:.1}", HSVA::green ()), "(120.0, 1.0, 1.0, 1.0)");
+    /// ```
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        parse::fmt_components (f, &[self.h.value, self.s, self.v, self.a])
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl FromStr for HSVA {
+
+    type Err = ParseError;
+
+    /// Parses a colour from a string such as `(120, 1, 0.5, 1)`, with the hue in degrees.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::HSVA;
+    /// let colour: HSVA = "(120, 1, 0.5, 1)".parse ().unwrap ();
+    /// ```
+    fn from_str (s: &str) -> Result<HSVA, ParseError> {
+
+        let c = parse::parse_components (s, 4)?;
+        Ok (HSVA {h: Deg::new (c[0]), s: c[1], v: c[2], a: c[3]})
+    }
+}
@@ -20,8 +20,12 @@
 
 // Modules
 mod colour_trait;
+mod hsla;
+mod hsva;
 mod rgba;
 
 // Module imports
 pub use self::colour_trait::ColourTrait;
+pub use self::hsla::HSLA;
+pub use self::hsva::HSVA;
 pub use self::rgba::RGBA;
@@ -20,16 +20,20 @@ extern crate num_traits;
 // Module imports
 use self::num_traits::{Num, NumCast};
 
-use ::colour::ColourTrait;
+use ::approx::ApproxEq;
+use ::colour::{ColourTrait, HSLA, HSVA};
+use ::parse::{self, ParseError};
 use ::util;
 use ::vector::{Vec2, Vec3, Vec4};
 
 use std::convert::From;
+use std::fmt;
 use std::ops::{Add,   AddAssign,
                Sub,   SubAssign,
                Mul,   MulAssign,
                Div,   DivAssign,
-               Index, IndexMut};
+               Index, IndexMut, Neg};
+use std::str::FromStr;
 
 /*===============================================================================================*/
 /*------RGBA STRUCT------------------------------------------------------------------------------*/
@@ -130,6 +134,69 @@ impl<'a, T> From<&'a Vec4<T>> for RGBA where
     }
 }
 
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a> From<&'a HSVA> for RGBA {
+
+    /// Converts a HSV colour to `RGBA`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Deg;
+    /// # use ion_math::colour::{ColourTrait, HSVA, RGBA};
+    /// # fn main () {
+    /// let colour = RGBA::from (&HSVA::new (Deg::new (60), 1.0, 1.0, 1.0));
+    /// assert_approx_eq! (colour, RGBA::yellow ());
+    /// # }
+    /// ```
+    fn from (hsva: &HSVA) -> RGBA {
+
+        let hue = hsva.h.normalize ().value / 60.0;
+
+        // Each channel follows the same piecewise curve, offset around the hue circle
+        let channel = |offset: f32| {
+
+            let k = (offset + hue) % 6.0;
+            hsva.v - hsva.v * hsva.s * k.min (4.0 - k).clamp (0.0, 1.0)
+        };
+
+        RGBA {r: channel (5.0), g: channel (3.0), b: channel (1.0), a: hsva.a}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a> From<&'a HSLA> for RGBA {
+
+    /// Converts a HSL colour to `RGBA`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Deg;
+    /// # use ion_math::colour::{ColourTrait, HSLA, RGBA};
+    /// # fn main () {
+    /// let colour = RGBA::from (&HSLA::new (Deg::new (180), 1.0, 0.5, 1.0));
+    /// assert_approx_eq! (colour, RGBA::cyan ());
+    /// # }
+    /// ```
+    fn from (hsla: &HSLA) -> RGBA {
+
+        let hue = hsla.h.normalize ().value / 30.0;
+        let range = hsla.s * hsla.l.min (1.0 - hsla.l);
+
+        // Each channel follows the same piecewise curve, offset around the hue circle
+        let channel = |offset: f32| {
+
+            let k = (offset + hue) % 12.0;
+            hsla.l - range * (k - 3.0).min (9.0 - k).clamp (-1.0, 1.0)
+        };
+
+        RGBA {r: channel (0.0), g: channel (8.0), b: channel (4.0), a: hsla.a}
+    }
+}
+
 /*===============================================================================================*/
 /*------OPERATORS--------------------------------------------------------------------------------*/
 /*===============================================================================================*/
@@ -598,6 +665,36 @@ impl DivAssign<f32> for RGBA {
 
 /*-----------------------------------------------------------------------------------------------*/
 
+impl Neg for RGBA {
+
+    type Output = RGBA;
+
+    fn neg (self) -> RGBA {
+
+        RGBA::new (-self.r,
+                   -self.g,
+                   -self.b,
+                   -self.a)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a> Neg for &'a RGBA {
+
+    type Output = RGBA;
+
+    fn neg (self) -> RGBA {
+
+        RGBA::new (-self.r,
+                   -self.g,
+                   -self.b,
+                   -self.a)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
 impl Index<u8> for RGBA {
 
     type Output = f32;
@@ -632,6 +729,26 @@ impl IndexMut<u8> for RGBA {
     }
 }
 
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Index<usize> for RGBA {
+
+    type Output = f32;
+
+    fn index (&self, index: usize) -> &f32 {
+        self.get (index).expect ("Index out of range for RGBA")
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl IndexMut<usize> for RGBA {
+
+    fn index_mut (&mut self, index: usize) -> &mut f32 {
+        self.get_mut (index).expect ("Index out of range for RGBA")
+    }
+}
+
 /*===============================================================================================*/
 /*------TRAIT IMPLEMENTATIONS--------------------------------------------------------------------*/
 /*===============================================================================================*/
@@ -812,3 +929,149 @@ impl ColourTrait for RGBA {
                    util::clamp (self.a, min.a, max.a))
     }
 }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl ApproxEq for RGBA {
+
+    type Epsilon = f32;
+
+    fn default_epsilon () -> f32 {
+        f32::default_epsilon ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_relative () -> f32 {
+        f32::default_max_relative ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_ulps () -> u32 {
+        f32::default_max_ulps ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn abs_diff_eq (&self, other: &RGBA, epsilon: f32) -> bool {
+
+        self.r.abs_diff_eq (&other.r, epsilon) &&
+        self.g.abs_diff_eq (&other.g, epsilon) &&
+        self.b.abs_diff_eq (&other.b, epsilon) &&
+        self.a.abs_diff_eq (&other.a, epsilon)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn relative_eq (&self, other: &RGBA, epsilon: f32, max_relative: f32) -> bool {
+
+        self.r.relative_eq (&other.r, epsilon, max_relative) &&
+        self.g.relative_eq (&other.g, epsilon, max_relative) &&
+        self.b.relative_eq (&other.b, epsilon, max_relative) &&
+        self.a.relative_eq (&other.a, epsilon, max_relative)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn ulps_eq (&self, other: &RGBA, epsilon: f32, max_ulps: u32) -> bool {
+
+        self.r.ulps_eq (&other.r, epsilon, max_ulps) &&
+        self.g.ulps_eq (&other.g, epsilon, max_ulps) &&
+        self.b.ulps_eq (&other.b, epsilon, max_ulps) &&
+        self.a.ulps_eq (&other.a, epsilon, max_ulps)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl fmt::Display for RGBA {
+
+    /// Formats the colour as `(r, g, b, a)`, honouring the precision flag.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::{ColourTrait, RGBA};
+    /// assert_eq! (format! ("{:.1}", RGBA::red ()), "(1.0, 0.0, 0.0, 1.0)");
+    /// ```
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        parse::fmt_components (f, &[self.r, self.g, self.b, self.a])
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl FromStr for RGBA {
+
+    type Err = ParseError;
+
+    /// Parses a colour from a string such as `(1, 0.5, 0, 1)`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::RGBA;
+    /// let colour: RGBA = "(1, 0.5, 0, 1)".parse ().unwrap ();
+    /// ```
+    fn from_str (s: &str) -> Result<RGBA, ParseError> {
+
+        let c = parse::parse_components (s, 4)?;
+        Ok (RGBA {r: c[0], g: c[1], b: c[2], a: c[3]})
+    }
+}
+
+/*===============================================================================================*/
+/*------PUBLIC METHODS---------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl RGBA {
+
+    /// Returns a reference to a channel, or `None` if the index is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::{ColourTrait, RGBA};
+    /// let colour = RGBA::red ();
+    ///
+    /// assert_eq! (colour.get (0), Some (&1.0));
+    /// assert_eq! (colour.get (4), None);
+    /// ```
+    pub fn get (&self, index: usize) -> Option<&f32> {
+
+        match index {
+
+            0 => Some (&self.r),
+            1 => Some (&self.g),
+            2 => Some (&self.b),
+            3 => Some (&self.a),
+            _ => None
+        }
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a mutable reference to a channel, or `None` if the index is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::colour::{ColourTrait, RGBA};
+    /// let mut colour = RGBA::white ();
+    ///
+    /// if let Some (alpha) = colour.get_mut (3) {
+    ///     *alpha = 0.5;
+    /// }
+    ///
+    /// colour[0usize] = 0.5;
+    /// assert_eq! (colour[1usize] * 2.0, 2.0);
+    /// ```
+    pub fn get_mut (&mut self, index: usize) -> Option<&mut f32> {
+
+        match index {
+
+            0 => Some (&mut self.r),
+            1 => Some (&mut self.g),
+            2 => Some (&mut self.b),
+            3 => Some (&mut self.a),
+            _ => None
+        }
+    }
+}
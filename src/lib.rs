@@ -40,7 +40,10 @@ extern crate serde_derive;
 
 // Modules
 pub mod angle;
+pub mod approx;
 pub mod colour;
 pub mod matrix;
+pub mod parse;
+pub mod quaternion;
 pub mod util;
 pub mod vector;
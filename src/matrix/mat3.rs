@@ -18,13 +18,19 @@
 extern crate num_traits;
 
 // Module imports
-use self::num_traits::{Num, NumCast};
+use self::num_traits::{Float, Num, NumCast};
 
+use ::angle::{EulerAngles, Rad};
+use ::approx::ApproxEq;
 use ::matrix::MatTrait;
-use ::vector::Vec3;
+use ::parse::{self, ParseError};
+use ::quaternion::Quat;
+use ::vector::{Vec3, VecTraitF};
 
 use std::convert::From;
+use std::fmt;
 use std::ops::{AddAssign, Index, IndexMut, Mul};
+use std::str::FromStr;
 
 /*===============================================================================================*/
 /*------MAT3 STRUCT------------------------------------------------------------------------------*/
@@ -83,6 +89,66 @@ impl<T> Mat3<T> where
 
 /*-----------------------------------------------------------------------------------------------*/
 
+impl<T> Mat3<T> where
+    T: Default + Float {
+
+    /// Returns the shortest rotation that turns one direction into another.
+    ///
+    /// The directions do not need to be normalized. When they are opposite, the <br>
+    /// rotation is half a turn around an axis perpendicular to `from`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::matrix::Mat3;
+    /// # use ion_math::vector::Vec3;
+    /// let mat = Mat3::<f32>::from_rotation_arc (&Vec3::forward (), &Vec3::up ());
+    ///
+    /// // The forward axis, in the last column, now points up
+    /// assert! ((mat[(1, 2)] - 1.0).abs () < 0.0001);
+    /// ```
+    pub fn from_rotation_arc (from: &Vec3<T>, to: &Vec3<T>) -> Mat3<T> {
+        Mat3::from (&Quat::from_rotation_arc (from, to))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a rotation that turns `Vec3::forward ()` towards a direction, <br>
+    /// keeping `Vec3::up ()` as close to an up direction as possible.
+    ///
+    /// The columns of the matrix are the rotated right, up and forward axes. <br>
+    /// When both directions are parallel, the shortest rotation is used instead.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::matrix::Mat3;
+    /// # use ion_math::vector::Vec3;
+    /// let mat = Mat3::<f32>::look_rotation (&Vec3::back (), &Vec3::up ());
+    ///
+    /// assert_eq! (mat, Mat3::new (-1,  0,  0,
+    ///                               0,  1,  0,
+    ///                               0,  0, -1));
+    /// ```
+    pub fn look_rotation (forward: &Vec3<T>, up: &Vec3<T>) -> Mat3<T> {
+
+        let forward = forward.normalize ();
+        let right = up.cross (&forward);
+        let length = right.length ();
+
+        if length < T::epsilon ().sqrt () {
+            return Mat3::from_rotation_arc (&Vec3::forward (), &forward);
+        }
+
+        let right = right / length;
+        let up = forward.cross (&right);
+
+        Mat3::new (right.x, up.x, forward.x,
+                   right.y, up.y, forward.y,
+                   right.z, up.z, forward.z)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
 impl<T, C> From<C> for Mat3<T> where
     T: Copy + Num + NumCast,
     C: Copy + Num + NumCast {
@@ -109,6 +175,59 @@ impl<'a, T, C> From<&'a Vec3<C>> for Mat3<T> where
     }
 }
 
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> From<&'a Quat<T>> for Mat3<T> where
+    T: Default + Float {
+
+    /// Converts a unit quaternion to a rotation matrix.
+    ///
+    /// The matrix is laid out for column vectors, so the first column is the rotated x-axis.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::matrix::Mat3;
+    /// # use ion_math::quaternion::Quat;
+    /// let quat = Quat::<f32>::from_rotation_z (Rad::new (1.2));
+    /// let mat = Mat3::from (&quat);
+    ///
+    /// assert! ((mat[(1, 0)] - 1.2_f32.sin ()).abs () < 0.0001);
+    /// ```
+    fn from (quat: &Quat<T>) -> Mat3<T> {
+
+        let (x2, y2, z2) = (quat.x + quat.x, quat.y + quat.y, quat.z + quat.z);
+        let (xx, yy, zz) = (quat.x * x2, quat.y * y2, quat.z * z2);
+        let (xy, xz, yz) = (quat.x * y2, quat.x * z2, quat.y * z2);
+        let (wx, wy, wz) = (quat.w * x2, quat.w * y2, quat.w * z2);
+        let one = T::one ();
+
+        Mat3::new (one - (yy + zz), xy - wz,         xz + wy,
+                   xy + wz,         one - (xx + zz), yz - wx,
+                   xz - wy,         yz + wx,         one - (xx + yy))
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> From<&'a EulerAngles<T>> for Mat3<T> where
+    T: Default + Float {
+
+    /// Converts Euler angles to a rotation matrix, following their rotation order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::{EulerAngles, EulerOrder, Rad};
+    /// # use ion_math::matrix::Mat3;
+    /// let euler = EulerAngles::<f32>::new (Rad::new (0.5), Rad::new (1.0), Rad::new (0.0),
+    ///                                      EulerOrder::IntrinsicYXZ);
+    /// let mat = Mat3::from (&euler);
+    /// ```
+    fn from (euler: &EulerAngles<T>) -> Mat3<T> {
+        Mat3::from (&Quat::from (euler))
+    }
+}
+
 /*===============================================================================================*/
 /*------OPERATORS--------------------------------------------------------------------------------*/
 /*===============================================================================================*/
@@ -123,11 +242,11 @@ impl<T> Mul for Mat3<T> where
 
         let mut m = Mat3::from (0);
 
-        for row in 0..3 {
+        for row in 0..3usize {
 
-            for col in 0..3 {
+            for col in 0..3usize {
 
-                for inner in 0..3 {
+                for inner in 0..3usize {
                     m[row][col] += self[row][inner] * rhs[inner][col]
                 }
             }
@@ -149,11 +268,11 @@ impl<'a, T> Mul<&'a Mat3<T>> for Mat3<T> where
 
         let mut m = Mat3::from (0);
 
-        for row in 0..3 {
+        for row in 0..3usize {
 
-            for col in 0..3 {
+            for col in 0..3usize {
 
-                for inner in 0..3 {
+                for inner in 0..3usize {
                     m[row][col] += self[row][inner] * rhs[inner][col]
                 }
             }
@@ -175,11 +294,11 @@ impl<'a, T> Mul<Mat3<T>> for &'a Mat3<T> where
 
         let mut m = Mat3::from (0);
 
-        for row in 0..3 {
+        for row in 0..3usize {
 
-            for col in 0..3 {
+            for col in 0..3usize {
 
-                for inner in 0..3 {
+                for inner in 0..3usize {
                     m[row][col] += self[row][inner] * rhs[inner][col]
                 }
             }
@@ -201,11 +320,11 @@ impl<'a, 'b, T> Mul<&'a Mat3<T>> for &'a Mat3<T> where
 
         let mut m = Mat3::from (0);
 
-        for row in 0..3 {
+        for row in 0..3usize {
 
-            for col in 0..3 {
+            for col in 0..3usize {
 
-                for inner in 0..3 {
+                for inner in 0..3usize {
                     m[row][col] += self[row][inner] * rhs[inner][col]
                 }
             }
@@ -251,6 +370,50 @@ impl<T> IndexMut<u8> for Mat3<T> where
     }
 }
 
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Index<usize> for Mat3<T> where
+    T: Copy + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn index (&self, index: usize) -> &Vec3<T> {
+        self.get (index).expect ("Index out of range for Mat3")
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> IndexMut<usize> for Mat3<T> where
+    T: Copy + Num + NumCast {
+
+    fn index_mut (&mut self, index: usize) -> &mut Vec3<T> {
+        self.get_mut (index).expect ("Index out of range for Mat3")
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Index<(usize, usize)> for Mat3<T> where
+    T: Copy + Num + NumCast {
+
+    type Output = T;
+
+    fn index (&self, (row, col): (usize, usize)) -> &T {
+        &self.array[row][col]
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> IndexMut<(usize, usize)> for Mat3<T> where
+    T: Copy + Num + NumCast {
+
+    fn index_mut (&mut self, (row, col): (usize, usize)) -> &mut T {
+        &mut self.array[row][col]
+    }
+}
+
 /*===============================================================================================*/
 /*------TRAIT IMPLEMENTATIONS--------------------------------------------------------------------*/
 /*===============================================================================================*/
@@ -272,3 +435,204 @@ impl<T> MatTrait for Mat3<T> where
                    0, 0, 1)
     }
 }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> ApproxEq for Mat3<T> where
+    T: ApproxEq + Copy + Num + NumCast {
+
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon () -> T::Epsilon {
+        T::default_epsilon ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_relative () -> T::Epsilon {
+        T::default_max_relative ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_ulps () -> u32 {
+        T::default_max_ulps ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn abs_diff_eq (&self, other: &Mat3<T>, epsilon: T::Epsilon) -> bool {
+
+        self.array[0].abs_diff_eq (&other.array[0], epsilon) &&
+        self.array[1].abs_diff_eq (&other.array[1], epsilon) &&
+        self.array[2].abs_diff_eq (&other.array[2], epsilon)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn relative_eq (&self, other: &Mat3<T>, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+
+        self.array[0].relative_eq (&other.array[0], epsilon, max_relative) &&
+        self.array[1].relative_eq (&other.array[1], epsilon, max_relative) &&
+        self.array[2].relative_eq (&other.array[2], epsilon, max_relative)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn ulps_eq (&self, other: &Mat3<T>, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+
+        self.array[0].ulps_eq (&other.array[0], epsilon, max_ulps) &&
+        self.array[1].ulps_eq (&other.array[1], epsilon, max_ulps) &&
+        self.array[2].ulps_eq (&other.array[2], epsilon, max_ulps)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> fmt::Display for Mat3<T> where
+    T: Copy + fmt::Display + Num + NumCast {
+
+    /// Formats the matrix as a grid, one row per line, honouring the width and precision flags.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::matrix::{Mat3, MatTrait};
+    /// let mat = Mat3::<i32>::identity ();
+    /// println! ("{}", mat);
+    /// ```
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        parse::fmt_grid (f, &[self.array[0].as_ref (), self.array[1].as_ref (), self.array[2].as_ref ()])
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> FromStr for Mat3<T> where
+    T: Copy + FromStr + Num + NumCast {
+
+    type Err = ParseError;
+
+    /// Parses a matrix from 9 components in row-major order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::matrix::{Mat3, MatTrait};
+    /// let text = format! ("{}", Mat3::<f32>::identity ());
+    /// let mat: Mat3<f32> = text.parse ().unwrap ();
+    ///
+    /// assert_eq! (mat, Mat3::identity ());
+    /// ```
+    fn from_str (s: &str) -> Result<Mat3<T>, ParseError> {
+
+        let c = parse::parse_components (s, 9)?;
+
+        Ok (Mat3 {
+
+            array: [Vec3 {x: c[0], y: c[1], z: c[2]},
+                    Vec3 {x: c[3], y: c[4], z: c[5]},
+                    Vec3 {x: c[6], y: c[7], z: c[8]}]
+        })
+    }
+}
+
+/*===============================================================================================*/
+/*------PUBLIC METHODS---------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Mat3<T> where
+    T: Copy + Num + NumCast {
+
+    /// Returns a reference to a row, or `None` if the index is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::matrix::{Mat3, MatTrait};
+    /// let mat = Mat3::<f32>::identity ();
+    ///
+    /// assert! (mat.get (0).is_some ());
+    /// assert! (mat.get (3).is_none ());
+    /// ```
+    pub fn get (&self, index: usize) -> Option<&Vec3<T>> {
+        self.array.get (index)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a mutable reference to a row, or `None` if the index is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::matrix::{Mat3, MatTrait};
+    /// let mut mat = Mat3::<f32>::identity ();
+    ///
+    /// if let Some (row) = mat.get_mut (1) {
+    ///     row.x = 2.0;
+    /// }
+    ///
+    /// assert_eq! (mat[(1, 0)], 2.0);
+    /// ```
+    pub fn get_mut (&mut self, index: usize) -> Option<&mut Vec3<T>> {
+        self.array.get_mut (index)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Mat3<T> where
+    T: Default + Float {
+
+    /// Splits a rotation matrix into a swing and a twist around an axis, such that <br>
+    /// `swing * twist` is the original rotation.
+    ///
+    /// See `Quat::swing_twist` for details.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::matrix::{Mat3, MatTrait};
+    /// # use ion_math::quaternion::Quat;
+    /// # use ion_math::vector::Vec3;
+    /// # fn main () {
+    /// let mat = Mat3::<f64>::from (&Quat::from_rotation_z (Rad::new (0.3)));
+    /// let (swing, twist) = mat.swing_twist (&Vec3::forward ());
+    ///
+    /// assert_approx_eq! (swing, Mat3::identity ());
+    /// assert_approx_eq! (twist, mat);
+    /// # }
+    /// ```
+    pub fn swing_twist (&self, axis: &Vec3<T>) -> (Mat3<T>, Mat3<T>) {
+
+        let (swing, twist) = Quat::from (self).swing_twist (axis);
+        (Mat3::from (&swing), Mat3::from (&twist))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Limits the swing and twist of a rotation matrix around an axis.
+    ///
+    /// See `Quat::clamp_swing_twist` for details.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::matrix::Mat3;
+    /// # use ion_math::quaternion::Quat;
+    /// # use ion_math::vector::Vec3;
+    /// # fn main () {
+    /// let mat = Mat3::<f64>::from (&Quat::from_rotation_z (Rad::new (0.3)));
+    /// let clamped = mat.clamp_swing_twist (&Vec3::forward (), Rad::new (0.0), Rad::new (0.0), Rad::new (0.1));
+    ///
+    /// assert_approx_eq! (clamped, Mat3::from (&Quat::from_rotation_z (Rad::new (0.1))));
+    /// # }
+    /// ```
+    pub fn clamp_swing_twist (&self,
+                              axis: &Vec3<T>,
+                              max_swing: Rad<T>,
+                              min_twist: Rad<T>,
+                              max_twist: Rad<T>) -> Mat3<T> {
+
+        Mat3::from (&Quat::from (self).clamp_swing_twist (axis, max_swing, min_twist, max_twist))
+    }
+}
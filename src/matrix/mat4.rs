@@ -18,13 +18,19 @@
 extern crate num_traits;
 
 // Module imports
-use self::num_traits::{Num, NumCast};
+use self::num_traits::{Float, Num, NumCast};
 
+use ::angle::EulerAngles;
+use ::approx::ApproxEq;
 use ::matrix::MatTrait;
+use ::parse::{self, ParseError};
+use ::quaternion::{DualQuat, Quat};
 use ::vector::Vec4;
 
 use std::convert::From;
+use std::fmt;
 use std::ops::{AddAssign, Index, IndexMut, Mul};
+use std::str::FromStr;
 
 /*===============================================================================================*/
 /*------MAT3 STRUCT------------------------------------------------------------------------------*/
@@ -113,6 +119,92 @@ impl<'a, T, C> From<&'a Vec4<C>> for Mat4<T> where
     }
 }
 
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> From<&'a Quat<T>> for Mat4<T> where
+    T: Default + Float {
+
+    /// Converts a unit quaternion to a rotation matrix with no translation.
+    ///
+    /// The matrix is laid out for column vectors, so the first column is the rotated x-axis.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::matrix::Mat4;
+    /// # use ion_math::quaternion::Quat;
+    /// let quat = Quat::<f32>::from_rotation_z (Rad::new (1.2));
+    /// let mat = Mat4::from (&quat);
+    ///
+    /// assert! ((mat[(1, 0)] - 1.2_f32.sin ()).abs () < 0.0001);
+    /// ```
+    fn from (quat: &Quat<T>) -> Mat4<T> {
+
+        let (x2, y2, z2) = (quat.x + quat.x, quat.y + quat.y, quat.z + quat.z);
+        let (xx, yy, zz) = (quat.x * x2, quat.y * y2, quat.z * z2);
+        let (xy, xz, yz) = (quat.x * y2, quat.x * z2, quat.y * z2);
+        let (wx, wy, wz) = (quat.w * x2, quat.w * y2, quat.w * z2);
+        let (zero, one) = (T::zero (), T::one ());
+
+        Mat4::new (one - (yy + zz), xy - wz,         xz + wy,         zero,
+                   xy + wz,         one - (xx + zz), yz - wx,         zero,
+                   xz - wy,         yz + wx,         one - (xx + yy), zero,
+                   zero,            zero,            zero,            one)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> From<&'a EulerAngles<T>> for Mat4<T> where
+    T: Default + Float {
+
+    /// Converts Euler angles to a rotation matrix, following their rotation order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::{EulerAngles, EulerOrder, Rad};
+    /// # use ion_math::matrix::Mat4;
+    /// let euler = EulerAngles::<f32>::new (Rad::new (0.5), Rad::new (1.0), Rad::new (0.0),
+    ///                                      EulerOrder::IntrinsicYXZ);
+    /// let mat = Mat4::from (&euler);
+    /// ```
+    fn from (euler: &EulerAngles<T>) -> Mat4<T> {
+        Mat4::from (&Quat::from (euler))
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> From<&'a DualQuat<T>> for Mat4<T> where
+    T: Default + Float {
+
+    /// Converts a unit dual quaternion to a rigid transformation matrix.
+    ///
+    /// The matrix is laid out for column vectors, so the translation is in the last column.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::matrix::Mat4;
+    /// # use ion_math::quaternion::DualQuat;
+    /// # use ion_math::vector::Vec3;
+    /// let dual_quat = DualQuat::<f32>::from_translation (&Vec3::new (1, 2, 3));
+    /// let mat = Mat4::from (&dual_quat);
+    ///
+    /// assert_eq! (mat[(2, 3)], 3.0);
+    /// ```
+    fn from (dual_quat: &DualQuat<T>) -> Mat4<T> {
+
+        let mut mat = Mat4::from (&dual_quat.real);
+        let translation = dual_quat.translation ();
+
+        mat[(0, 3)] = translation.x;
+        mat[(1, 3)] = translation.y;
+        mat[(2, 3)] = translation.z;
+
+        mat
+    }
+}
+
 /*===============================================================================================*/
 /*------OPERATORS--------------------------------------------------------------------------------*/
 /*===============================================================================================*/
@@ -127,11 +219,11 @@ impl<T> Mul for Mat4<T> where
 
         let mut m = Mat4::from (0);
 
-        for row in 0..4 {
+        for row in 0..4usize {
 
-            for col in 0..4 {
+            for col in 0..4usize {
 
-                for inner in 0..4 {
+                for inner in 0..4usize {
                     m[row][col] += self[row][inner] * rhs[inner][col]
                 }
             }
@@ -153,11 +245,11 @@ impl<'a, T> Mul<&'a Mat4<T>> for Mat4<T> where
 
         let mut m = Mat4::from (0);
 
-        for row in 0..4 {
+        for row in 0..4usize {
 
-            for col in 0..4 {
+            for col in 0..4usize {
 
-                for inner in 0..4 {
+                for inner in 0..4usize {
                     m[row][col] += self[row][inner] * rhs[inner][col]
                 }
             }
@@ -179,11 +271,11 @@ impl<'a, T> Mul<Mat4<T>> for &'a Mat4<T> where
 
         let mut m = Mat4::from (0);
 
-        for row in 0..4 {
+        for row in 0..4usize {
 
-            for col in 0..4 {
+            for col in 0..4usize {
 
-                for inner in 0..4 {
+                for inner in 0..4usize {
                     m[row][col] += self[row][inner] * rhs[inner][col]
                 }
             }
@@ -205,11 +297,11 @@ impl<'a, 'b, T> Mul<&'a Mat4<T>> for &'a Mat4<T> where
 
         let mut m = Mat4::from (0);
 
-        for row in 0..4 {
+        for row in 0..4usize {
 
-            for col in 0..4 {
+            for col in 0..4usize {
 
-                for inner in 0..4 {
+                for inner in 0..4usize {
                     m[row][col] += self[row][inner] * rhs[inner][col]
                 }
             }
@@ -257,6 +349,50 @@ impl<T> IndexMut<u8> for Mat4<T> where
     }
 }
 
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Index<usize> for Mat4<T> where
+    T: Copy + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn index (&self, index: usize) -> &Vec4<T> {
+        self.get (index).expect ("Index out of range for Mat4")
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> IndexMut<usize> for Mat4<T> where
+    T: Copy + Num + NumCast {
+
+    fn index_mut (&mut self, index: usize) -> &mut Vec4<T> {
+        self.get_mut (index).expect ("Index out of range for Mat4")
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Index<(usize, usize)> for Mat4<T> where
+    T: Copy + Num + NumCast {
+
+    type Output = T;
+
+    fn index (&self, (row, col): (usize, usize)) -> &T {
+        &self.array[row][col]
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> IndexMut<(usize, usize)> for Mat4<T> where
+    T: Copy + Num + NumCast {
+
+    fn index_mut (&mut self, (row, col): (usize, usize)) -> &mut T {
+        &mut self.array[row][col]
+    }
+}
+
 /*===============================================================================================*/
 /*------TRAIT IMPLEMENTATIONS--------------------------------------------------------------------*/
 /*===============================================================================================*/
@@ -279,3 +415,147 @@ impl<T> MatTrait for Mat4<T> where
                    0, 0, 0, 1)
     }
 }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> ApproxEq for Mat4<T> where
+    T: ApproxEq + Copy + Num + NumCast {
+
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon () -> T::Epsilon {
+        T::default_epsilon ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_relative () -> T::Epsilon {
+        T::default_max_relative ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_ulps () -> u32 {
+        T::default_max_ulps ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn abs_diff_eq (&self, other: &Mat4<T>, epsilon: T::Epsilon) -> bool {
+
+        self.array[0].abs_diff_eq (&other.array[0], epsilon) &&
+        self.array[1].abs_diff_eq (&other.array[1], epsilon) &&
+        self.array[2].abs_diff_eq (&other.array[2], epsilon) &&
+        self.array[3].abs_diff_eq (&other.array[3], epsilon)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn relative_eq (&self, other: &Mat4<T>, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+
+        self.array[0].relative_eq (&other.array[0], epsilon, max_relative) &&
+        self.array[1].relative_eq (&other.array[1], epsilon, max_relative) &&
+        self.array[2].relative_eq (&other.array[2], epsilon, max_relative) &&
+        self.array[3].relative_eq (&other.array[3], epsilon, max_relative)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn ulps_eq (&self, other: &Mat4<T>, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+
+        self.array[0].ulps_eq (&other.array[0], epsilon, max_ulps) &&
+        self.array[1].ulps_eq (&other.array[1], epsilon, max_ulps) &&
+        self.array[2].ulps_eq (&other.array[2], epsilon, max_ulps) &&
+        self.array[3].ulps_eq (&other.array[3], epsilon, max_ulps)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> fmt::Display for Mat4<T> where
+    T: Copy + fmt::Display + Num + NumCast {
+
+    /// Formats the matrix as a grid, one row per line, honouring the width and precision flags.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::matrix::{Mat4, MatTrait};
+    /// let mat = Mat4::<i32>::identity ();
+    /// println! ("{}", mat);
+    /// ```
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        parse::fmt_grid (f, &[self.array[0].as_ref (), self.array[1].as_ref (), self.array[2].as_ref (), self.array[3].as_ref ()])
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> FromStr for Mat4<T> where
+    T: Copy + FromStr + Num + NumCast {
+
+    type Err = ParseError;
+
+    /// Parses a matrix from 16 components in row-major order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::matrix::{Mat4, MatTrait};
+    /// let text = format! ("{}", Mat4::<f32>::identity ());
+    /// let mat: Mat4<f32> = text.parse ().unwrap ();
+    ///
+    /// assert_eq! (mat, Mat4::identity ());
+    /// ```
+    fn from_str (s: &str) -> Result<Mat4<T>, ParseError> {
+
+        let c = parse::parse_components (s, 16)?;
+
+        Ok (Mat4 {
+
+            array: [Vec4 {x: c[0], y: c[1], z: c[2], w: c[3]},
+                    Vec4 {x: c[4], y: c[5], z: c[6], w: c[7]},
+                    Vec4 {x: c[8], y: c[9], z: c[10], w: c[11]},
+                    Vec4 {x: c[12], y: c[13], z: c[14], w: c[15]}]
+        })
+    }
+}
+
+/*===============================================================================================*/
+/*------PUBLIC METHODS---------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Mat4<T> where
+    T: Copy + Num + NumCast {
+
+    /// Returns a reference to a row, or `None` if the index is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::matrix::{Mat4, MatTrait};
+    /// let mat = Mat4::<f32>::identity ();
+    ///
+    /// assert! (mat.get (0).is_some ());
+    /// assert! (mat.get (4).is_none ());
+    /// ```
+    pub fn get (&self, index: usize) -> Option<&Vec4<T>> {
+        self.array.get (index)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a mutable reference to a row, or `None` if the index is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::matrix::{Mat4, MatTrait};
+    /// let mut mat = Mat4::<f32>::identity ();
+    ///
+    /// if let Some (row) = mat.get_mut (1) {
+    ///     row.x = 2.0;
+    /// }
+    ///
+    /// assert_eq! (mat[(1, 0)], 2.0);
+    /// ```
+    pub fn get_mut (&mut self, index: usize) -> Option<&mut Vec4<T>> {
+        self.array.get_mut (index)
+    }
+}
@@ -0,0 +1,235 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+/*===============================================================================================*/
+//! Parsing and formatting support for the math types.
+//!
+//! All types accept a list of numbers separated by commas or whitespace, optionally <br>
+//! surrounded by brackets, e.g. `(1, 2, 3)`, `[1, 2, 3]` or `1 2 3`. <br>
+//! Matrices are read in row-major order.
+//!
+//! Angles are a single number followed by an optional unit: `deg` or `°`, `rad`, <br>
+//! `turn` or `turns`, and `grad` or `gon`, e.g. `45°` or `0.25turn`. Without a unit, <br>
+//! the value is read in the unit of the type being parsed.
+/*===============================================================================================*/
+
+// Crate imports
+extern crate num_traits;
+
+// Module imports
+use self::num_traits::{Float, NumCast};
+
+use ::angle::{Deg, Gradians, Rad, Turns};
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/*===============================================================================================*/
+/*------PARSE ERROR ENUM-------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The error returned when parsing a math type from a string fails.
+#[derive (Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+
+    /// The string did not contain any components.
+    Empty,
+    /// The string contained the wrong number of components.
+    ComponentCount {
+        /// The number of components the type requires.
+        expected: usize,
+        /// The number of components found in the string.
+        found: usize,
+    },
+    /// The component at the given index is not a valid number.
+    InvalidComponent (usize),
+    /// An opening or closing bracket has no match, or is closed by the other kind of bracket.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::parse::ParseError;
+    /// # use ion_math::vector::Vec2;
+    /// assert_eq! ("(1, 2]".parse::<Vec2<f32>> (), Err (ParseError::UnmatchedBracket));
+    /// assert_eq! ("[1, 2".parse::<Vec2<f32>> (), Err (ParseError::UnmatchedBracket));
+    /// ```
+    UnmatchedBracket,
+    /// The unit following an angle is not recognised.
+    UnknownUnit,
+}
+
+/*===============================================================================================*/
+/*------TRAIT IMPLEMENTATIONS--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl fmt::Display for ParseError {
+
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+
+        match *self {
+
+            ParseError::Empty =>
+                write! (f, "cannot parse from an empty string"),
+            ParseError::ComponentCount {expected, found} =>
+                write! (f, "expected {} components, found {}", expected, found),
+            ParseError::InvalidComponent (index) =>
+                write! (f, "component {} is not a valid number", index),
+            ParseError::UnmatchedBracket =>
+                write! (f, "unmatched bracket"),
+            ParseError::UnknownUnit =>
+                write! (f, "unknown angle unit"),
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl Error for ParseError {}
+
+/*===============================================================================================*/
+/*------CRATE FUNCTIONS--------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Parses exactly `count` components from a string.
+pub(crate) fn parse_components<T> (s: &str, count: usize) -> Result<Vec<T>, ParseError> where
+    T: FromStr {
+
+    let mut open_brackets = Vec::new ();
+
+    for c in s.chars () {
+
+        match c {
+
+            '(' | '[' => open_brackets.push (c),
+            ')' | ']' => {
+
+                let opening = if c == ')' {'('} else {'['};
+
+                if open_brackets.pop () != Some (opening) {
+                    return Err (ParseError::UnmatchedBracket);
+                }
+            },
+            _         => {}
+        }
+    }
+
+    if !open_brackets.is_empty () {
+        return Err (ParseError::UnmatchedBracket);
+    }
+
+    let tokens: Vec<&str> = s.split (|c: char| c.is_whitespace () || "()[],;".contains (c))
+                             .filter (|token| !token.is_empty ())
+                             .collect ();
+
+    if tokens.is_empty () {
+        return Err (ParseError::Empty);
+    }
+
+    if tokens.len () != count {
+        return Err (ParseError::ComponentCount {expected: count, found: tokens.len ()});
+    }
+
+    tokens.iter ()
+          .enumerate ()
+          .map (|(index, token)| token.parse::<T> ().map_err (|_| ParseError::InvalidComponent (index)))
+          .collect ()
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+/// Formats a single component, honouring the precision of the formatter.
+pub(crate) fn format_component<T> (f: &fmt::Formatter, value: &T) -> String where
+    T: fmt::Display {
+
+    match f.precision () {
+
+        Some (precision) => format! ("{:.*}", precision, value),
+        None             => format! ("{}", value),
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+/// Writes a list of components as `(a, b, c)`.
+pub(crate) fn fmt_components<T> (f: &mut fmt::Formatter, values: &[T]) -> fmt::Result where
+    T: fmt::Display {
+
+    let components: Vec<String> = values.iter ().map (|value| format_component (f, value)).collect ();
+    write! (f, "({})", components.join (", "))
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+/// Writes the rows of a matrix as a grid with aligned columns.
+pub(crate) fn fmt_grid<T> (f: &mut fmt::Formatter, rows: &[&[T]]) -> fmt::Result where
+    T: fmt::Display {
+
+    let cells: Vec<Vec<String>> = rows.iter ()
+                                      .map (|row| row.iter ().map (|value| format_component (f, value)).collect ())
+                                      .collect ();
+
+    let mut widths = vec! [f.width ().unwrap_or (0); rows[0].len ()];
+
+    for row in &cells {
+
+        for (width, cell) in widths.iter_mut ().zip (row) {
+            *width = (*width).max (cell.chars ().count ());
+        }
+    }
+
+    for (index, row) in cells.iter ().enumerate () {
+
+        if index > 0 {
+            writeln! (f)?;
+        }
+
+        let padded: Vec<String> = row.iter ()
+                                     .zip (&widths)
+                                     .map (|(cell, width)| format! ("{:>1$}", cell, width))
+                                     .collect ();
+
+        write! (f, "[{}]", padded.join (", "))?;
+    }
+
+    Ok (())
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+/// Parses an angle with an optional unit suffix, converting it to the requested type.
+///
+/// `native` builds the angle when no unit is given.
+pub(crate) fn parse_angle<A, T> (s: &str, native: fn (T) -> A) -> Result<A, ParseError> where
+    A: From<Deg<T>> + From<Gradians<T>> + From<Rad<T>> + From<Turns<T>>,
+    T: Copy + Float + FromStr + NumCast {
+
+    let s = s.trim ();
+    let number_end = s.rfind (|c: char| c.is_ascii_digit () || ".)]".contains (c))
+                      .map_or (0, |index| index + 1);
+
+    let (number, unit) = s.split_at (number_end);
+    let value = parse_components::<T> (number, 1)?[0];
+
+    match unit.trim () {
+
+        ""               => Ok (native (value)),
+        "deg" | "°"      => Ok (A::from (Deg {value})),
+        "rad"            => Ok (A::from (Rad {value})),
+        "turn" | "turns" => Ok (A::from (Turns {value})),
+        "grad" | "gon"   => Ok (A::from (Gradians {value})),
+        _                => Err (ParseError::UnknownUnit),
+    }
+}
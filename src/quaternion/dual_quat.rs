@@ -0,0 +1,623 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+// Crate imports
+extern crate num_traits;
+
+// Module imports
+use self::num_traits::Float;
+
+use ::approx::ApproxEq;
+use ::matrix::Mat4;
+use ::parse::{self, ParseError};
+use ::quaternion::Quat;
+use ::vector::{Vec3, VecTraitF};
+
+use std::convert::From;
+use std::fmt;
+use std::ops::{Mul, MulAssign};
+use std::str::FromStr;
+
+/*===============================================================================================*/
+/*------DUAL QUAT STRUCT-------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The generic `DualQuat` struct.
+///
+/// It represents a rigid transformation, a rotation followed by a translation, <br>
+/// and is mainly used for skinning. `lhs * rhs` applies `rhs` first, then `lhs`.
+#[cfg_attr (feature = "serde_serialize", derive (Deserialize, Serialize))]
+#[derive (Copy, Clone, Debug, PartialEq)]
+pub struct DualQuat<T> where
+    T: Default + Float {
+
+    // Public
+    /// The real part, holding the rotation.
+    pub real: Quat<T>,
+    /// The dual part, holding the translation.
+    pub dual: Quat<T>,
+}
+
+// Predefined DualQuat types
+/// `DualQuat<f32>`
+pub type DualQuatf = DualQuat<f32>;
+
+/*===============================================================================================*/
+/*------CONSTRUCTORS-----------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> DualQuat<T> where
+    T: Default + Float {
+
+    /// Returns a new `DualQuat` instance from its real and dual parts.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::quaternion::{DualQuat, Quat};
+    /// let dual_quat = DualQuat::<f32>::new (Quat::identity (), Quat::new (0, 0, 0, 0));
+    /// ```
+    pub fn new (real: Quat<T>, dual: Quat<T>) -> DualQuat<T> {
+        DualQuat {real, dual}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a dual quaternion representing no transformation.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::quaternion::DualQuat;
+    /// let dual_quat = DualQuat::<f32>::identity ();
+    /// ```
+    pub fn identity () -> DualQuat<T> {
+        DualQuat {real: Quat::identity (), dual: Quat::new (0, 0, 0, 0)}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a dual quaternion that rotates, then translates.
+    ///
+    /// The rotation must be a unit quaternion.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::quaternion::{DualQuat, Quat};
+    /// # use ion_math::vector::Vec3;
+    /// let rotation = Quat::<f32>::from_rotation_y (Rad::new (1.0));
+    /// let dual_quat = DualQuat::from_rotation_translation (&rotation, &Vec3::new (1, 2, 3));
+    /// ```
+    pub fn from_rotation_translation (rotation: &Quat<T>, translation: &Vec3<T>) -> DualQuat<T> {
+
+        let half = T::from (0.5).unwrap ();
+        let translation = Quat {x: translation.x * half,
+                                y: translation.y * half,
+                                z: translation.z * half,
+                                w: T::zero ()};
+
+        DualQuat {real: *rotation, dual: translation * rotation}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a dual quaternion that only rotates.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::quaternion::{DualQuat, Quat};
+    /// let dual_quat = DualQuat::<f32>::from_rotation (&Quat::from_rotation_x (Rad::new (0.5)));
+    /// ```
+    pub fn from_rotation (rotation: &Quat<T>) -> DualQuat<T> {
+        DualQuat {real: *rotation, dual: Quat::new (0, 0, 0, 0)}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a dual quaternion that only translates.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::quaternion::DualQuat;
+    /// # use ion_math::vector::Vec3;
+    /// let dual_quat = DualQuat::<f32>::from_translation (&Vec3::new (1, 2, 3));
+    /// ```
+    pub fn from_translation (translation: &Vec3<T>) -> DualQuat<T> {
+        DualQuat::from_rotation_translation (&Quat::identity (), translation)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> From<&'a Mat4<T>> for DualQuat<T> where
+    T: Default + Float {
+
+    /// Converts a rigid transformation matrix to a dual quaternion.
+    ///
+    /// The upper-left 3x3 part of the matrix must be orthonormal, and the translation <br>
+    /// is read from the last column.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::matrix::Mat4;
+    /// # use ion_math::quaternion::{DualQuat, Quat};
+    /// # use ion_math::vector::Vec3;
+    /// # fn main () {
+    /// let dual_quat = DualQuat::<f64>::from_rotation_translation (&Quat::from_rotation_z (Rad::new (1.0)),
+    ///                                                             &Vec3::new (4, 5, 6));
+    /// let mat = Mat4::from (&dual_quat);
+    ///
+    /// assert_approx_eq! (DualQuat::from (&mat), dual_quat);
+    /// # }
+    /// ```
+    fn from (mat: &Mat4<T>) -> DualQuat<T> {
+
+        DualQuat::from_rotation_translation (&Quat::from (mat),
+                                             &Vec3 {x: mat[(0, 3)], y: mat[(1, 3)], z: mat[(2, 3)]})
+    }
+}
+
+/*===============================================================================================*/
+/*------OPERATORS--------------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Mul for DualQuat<T> where
+    T: Default + Float {
+
+    type Output = DualQuat<T>;
+
+    fn mul (self, rhs: DualQuat<T>) -> DualQuat<T> {
+
+        DualQuat {real: self.real * rhs.real,
+                  dual: add (&(self.real * rhs.dual), &(self.dual * rhs.real))}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Mul<&'a DualQuat<T>> for DualQuat<T> where
+    T: Default + Float {
+
+    type Output = DualQuat<T>;
+
+    fn mul (self, rhs: &DualQuat<T>) -> DualQuat<T> {
+
+        DualQuat {real: self.real * rhs.real,
+                  dual: add (&(self.real * rhs.dual), &(self.dual * rhs.real))}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Mul<DualQuat<T>> for &'a DualQuat<T> where
+    T: Default + Float {
+
+    type Output = DualQuat<T>;
+
+    fn mul (self, rhs: DualQuat<T>) -> DualQuat<T> {
+
+        DualQuat {real: self.real * rhs.real,
+                  dual: add (&(self.real * rhs.dual), &(self.dual * rhs.real))}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> Mul<&'a DualQuat<T>> for &'b DualQuat<T> where
+    T: Default + Float {
+
+    type Output = DualQuat<T>;
+
+    fn mul (self, rhs: &DualQuat<T>) -> DualQuat<T> {
+
+        DualQuat {real: self.real * rhs.real,
+                  dual: add (&(self.real * rhs.dual), &(self.dual * rhs.real))}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> MulAssign for DualQuat<T> where
+    T: Default + Float {
+
+    fn mul_assign (&mut self, rhs: DualQuat<T>) {
+        *self = *self * rhs;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> MulAssign<&'a DualQuat<T>> for DualQuat<T> where
+    T: Default + Float {
+
+    fn mul_assign (&mut self, rhs: &DualQuat<T>) {
+        *self = *self * rhs;
+    }
+}
+
+/*===============================================================================================*/
+/*------TRAIT IMPLEMENTATIONS--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Default for DualQuat<T> where
+    T: Default + Float {
+
+    /// Returns the identity dual quaternion.
+    fn default () -> DualQuat<T> {
+        DualQuat::identity ()
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> ApproxEq for DualQuat<T> where
+    T: ApproxEq + Default + Float {
+
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon () -> T::Epsilon {
+        T::default_epsilon ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_relative () -> T::Epsilon {
+        T::default_max_relative ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_ulps () -> u32 {
+        T::default_max_ulps ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn abs_diff_eq (&self, other: &DualQuat<T>, epsilon: T::Epsilon) -> bool {
+
+        self.real.abs_diff_eq (&other.real, epsilon) &&
+        self.dual.abs_diff_eq (&other.dual, epsilon)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn relative_eq (&self, other: &DualQuat<T>, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+
+        self.real.relative_eq (&other.real, epsilon, max_relative) &&
+        self.dual.relative_eq (&other.dual, epsilon, max_relative)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn ulps_eq (&self, other: &DualQuat<T>, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+
+        self.real.ulps_eq (&other.real, epsilon, max_ulps) &&
+        self.dual.ulps_eq (&other.dual, epsilon, max_ulps)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> fmt::Display for DualQuat<T> where
+    T: Default + Float + fmt::Display {
+
+    /// Formats the dual quaternion as the real part followed by the dual part, <br>
+    /// honouring the precision flag.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::quaternion::DualQuat;
+    /// let dual_quat = DualQuat::<f32>::identity ();
+    /// assert_eq! (format! ("{}", dual_quat), "(0, 0, 0, 1, 0, 0, 0, 0)");
+    /// ```
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+
+        parse::fmt_components (f, &[self.real.x, self.real.y, self.real.z, self.real.w,
+                                    self.dual.x, self.dual.y, self.dual.z, self.dual.w])
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> FromStr for DualQuat<T> where
+    T: Default + Float + FromStr {
+
+    type Err = ParseError;
+
+    /// Parses a dual quaternion from eight numbers, the real part followed by the dual part.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::quaternion::DualQuat;
+    /// let dual_quat: DualQuat<f32> = "(0, 0, 0, 1, 0, 0, 0, 0)".parse ().unwrap ();
+    /// assert_eq! (dual_quat, DualQuat::identity ());
+    /// ```
+    fn from_str (s: &str) -> Result<DualQuat<T>, ParseError> {
+
+        let c = parse::parse_components (s, 8)?;
+
+        Ok (DualQuat {real: Quat {x: c[0], y: c[1], z: c[2], w: c[3]},
+                      dual: Quat {x: c[4], y: c[5], z: c[6], w: c[7]}})
+    }
+}
+
+/*===============================================================================================*/
+/*------PUBLIC METHODS---------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> DualQuat<T> where
+    T: Default + Float {
+
+    /// Returns the rotation part of a unit dual quaternion.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::quaternion::DualQuat;
+    /// let rotation = DualQuat::<f32>::identity ().rotation ();
+    /// ```
+    pub fn rotation (&self) -> Quat<T> {
+        self.real
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the translation part of a unit dual quaternion.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::quaternion::{DualQuat, Quat};
+    /// # use ion_math::vector::Vec3;
+    /// # fn main () {
+    /// let rotation = Quat::<f64>::from_rotation_x (Rad::new (2.0));
+    /// let dual_quat = DualQuat::from_rotation_translation (&rotation, &Vec3::new (1, 2, 3));
+    ///
+    /// assert_approx_eq! (dual_quat.translation (), Vec3::new (1.0, 2.0, 3.0));
+    /// # }
+    /// ```
+    pub fn translation (&self) -> Vec3<T> {
+
+        let two = T::one () + T::one ();
+        let translation = self.dual * self.real.conjugate ();
+
+        Vec3 {x: translation.x * two,
+              y: translation.y * two,
+              z: translation.z * two}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the conjugate of a dual quaternion.
+    ///
+    /// For a unit dual quaternion this is the inverse transformation.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::quaternion::{DualQuat, Quat};
+    /// # use ion_math::vector::Vec3;
+    /// # fn main () {
+    /// let rotation = Quat::<f64>::from_rotation_y (Rad::new (0.5));
+    /// let dual_quat = DualQuat::from_rotation_translation (&rotation, &Vec3::new (1, 2, 3));
+    ///
+    /// assert_approx_eq! (dual_quat * dual_quat.conjugate (), DualQuat::identity ());
+    /// # }
+    /// ```
+    pub fn conjugate (&self) -> DualQuat<T> {
+        DualQuat {real: self.real.conjugate (), dual: self.dual.conjugate ()}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a unit dual quaternion, with a unit real part and a dual part <br>
+    /// orthogonal to it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::quaternion::{DualQuat, Quat};
+    /// let dual_quat = DualQuat::<f32>::new (Quat::new (0, 0, 0, 2), Quat::new (1, 0, 0, 1));
+    /// let unit = dual_quat.normalize ();
+    ///
+    /// assert_eq! (unit.real, Quat::identity ());
+    /// assert_eq! (unit.dual, Quat::new (0.5, 0.0, 0.0, 0.0));
+    /// ```
+    pub fn normalize (&self) -> DualQuat<T> {
+
+        let length = self.real.length ();
+
+        if length == T::zero () {
+            return DualQuat::identity ();
+        }
+
+        let real = scale (&self.real, T::one () / length);
+        let dual = scale (&self.dual, T::one () / length);
+
+        DualQuat {real, dual: add (&dual, &scale (&real, -real.dot (&dual)))}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Transforms a point, applying the rotation and then the translation.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::quaternion::{DualQuat, Quat};
+    /// # use ion_math::vector::Vec3;
+    /// # fn main () {
+    /// let rotation = Quat::<f64>::from_rotation_z (Rad::new (std::f64::consts::FRAC_PI_2));
+    /// let dual_quat = DualQuat::from_rotation_translation (&rotation, &Vec3::new (0, 0, 5));
+    ///
+    /// assert_approx_eq! (dual_quat.transform_point (&Vec3::new (1, 0, 0)), Vec3::new (0.0, 1.0, 5.0));
+    /// # }
+    /// ```
+    pub fn transform_point (&self, point: &Vec3<T>) -> Vec3<T> {
+        self.real * point + self.translation ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Transforms a direction, applying only the rotation.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::quaternion::DualQuat;
+    /// # use ion_math::vector::Vec3;
+    /// let dual_quat = DualQuat::<f32>::from_translation (&Vec3::new (1, 2, 3));
+    ///
+    /// assert_eq! (dual_quat.transform_vector (&Vec3::new (0, 1, 0)), Vec3::new (0, 1, 0));
+    /// ```
+    pub fn transform_vector (&self, vector: &Vec3<T>) -> Vec3<T> {
+        self.real * vector
+    }
+
+/*===============================================================================================*/
+/*------PUBLIC STATIC METHODS--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Blends several unit dual quaternions by weight, using dual quaternion linear blending.
+    ///
+    /// Each dual quaternion is flipped into the same hemisphere as the first before <br>
+    /// blending, so the result takes the shortest path. Unlike blending matrices, this <br>
+    /// keeps the volume of skinned meshes around twisting joints.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::quaternion::{DualQuat, Quat};
+    /// # use ion_math::vector::Vec3;
+    /// # fn main () {
+    /// let bones = [DualQuat::<f64>::identity (),
+    ///              DualQuat::from_translation (&Vec3::new (2, 0, 0))];
+    ///
+    /// let blended = DualQuat::blend (&bones, &[0.5, 0.5]);
+    /// assert_approx_eq! (blended.translation (), Vec3::new (1.0, 0.0, 0.0));
+    /// # }
+    /// ```
+    pub fn blend (dual_quats: &[DualQuat<T>], weights: &[T]) -> DualQuat<T> {
+
+        debug_assert! (dual_quats.len () == weights.len (), "Each dual quaternion needs a weight.");
+
+        let zero = Quat::new (0, 0, 0, 0);
+        let mut result = DualQuat {real: zero, dual: zero};
+
+        for (dual_quat, &weight) in dual_quats.iter ().zip (weights) {
+
+            let weight = if dual_quat.real.dot (&dual_quats[0].real) < T::zero () {-weight} else {weight};
+
+            result.real = add (&result.real, &scale (&dual_quat.real, weight));
+            result.dual = add (&result.dual, &scale (&dual_quat.dual, weight));
+        }
+
+        result.normalize ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Interpolates between two unit dual quaternions with screw linear interpolation.
+    ///
+    /// The transformation moves along a single screw motion at a constant speed, <br>
+    /// taking the shortest path.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::quaternion::{DualQuat, Quat};
+    /// # use ion_math::vector::Vec3;
+    /// # fn main () {
+    /// let start = DualQuat::<f64>::identity ();
+    /// let end = DualQuat::from_rotation_translation (&Quat::from_rotation_y (Rad::new (1.0)),
+    ///                                                &Vec3::new (0, 4, 0));
+    /// let halfway = DualQuat::sclerp (&start, &end, 0.5);
+    ///
+    /// assert_approx_eq! (halfway.rotation (), Quat::from_rotation_y (Rad::new (0.5)));
+    /// assert_approx_eq! (halfway.translation (), Vec3::new (0.0, 2.0, 0.0));
+    /// # }
+    /// ```
+    pub fn sclerp (start: &DualQuat<T>, end: &DualQuat<T>, percentage: T) -> DualQuat<T> {
+
+        let mut difference = start.conjugate () * end;
+
+        // Take the shortest path
+        if difference.real.w < T::zero () {
+
+            difference.real = -difference.real;
+            difference.dual = -difference.dual;
+        }
+
+        start * power (&difference, percentage)
+    }
+}
+
+/*===============================================================================================*/
+/*------PRIVATE FUNCTIONS------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// Adds two quaternions component-wise.
+fn add<T> (lhs: &Quat<T>, rhs: &Quat<T>) -> Quat<T> where
+    T: Default + Float {
+
+    Quat {x: lhs.x + rhs.x, y: lhs.y + rhs.y, z: lhs.z + rhs.z, w: lhs.w + rhs.w}
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+// Multiplies each component of a quaternion by a scalar.
+fn scale<T> (quat: &Quat<T>, scalar: T) -> Quat<T> where
+    T: Default + Float {
+
+    Quat {x: quat.x * scalar, y: quat.y * scalar, z: quat.z * scalar, w: quat.w * scalar}
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+// Raises a unit dual quaternion with a non-negative real w to a power, by scaling the
+// angle and displacement of its screw motion.
+fn power<T> (dual_quat: &DualQuat<T>, exponent: T) -> DualQuat<T> where
+    T: Default + Float {
+
+    let (real, dual) = (dual_quat.real, dual_quat.dual);
+    let two = T::one () + T::one ();
+    let sin_half = Vec3 {x: real.x, y: real.y, z: real.z}.length ();
+
+    // A pure translation has no screw axis, so scale the translation directly
+    if sin_half <= T::epsilon () {
+        return DualQuat::from_translation (&(dual_quat.translation () * exponent));
+    }
+
+    let direction = Vec3 {x: real.x / sin_half, y: real.y / sin_half, z: real.z / sin_half};
+    let angle = sin_half.atan2 (real.w) * two;
+    let displacement = -two * dual.w / sin_half;
+    let moment = (Vec3 {x: dual.x, y: dual.y, z: dual.z} -
+                  direction * (displacement * real.w / two)) / sin_half;
+
+    let (sin, cos) = (angle * exponent / two).sin_cos ();
+    let half_displacement = displacement * exponent / two;
+    let dual_vector = moment * sin + direction * (half_displacement * cos);
+
+    DualQuat {real: Quat {x: direction.x * sin, y: direction.y * sin, z: direction.z * sin, w: cos},
+              dual: Quat {x: dual_vector.x,
+                          y: dual_vector.y,
+                          z: dual_vector.z,
+                          w: -half_displacement * sin}}
+}
@@ -0,0 +1,1153 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+// Crate imports
+extern crate num_traits;
+
+// Module imports
+use self::num_traits::{Float, Num, NumCast};
+
+use ::angle::{EulerAngles, Rad};
+use ::approx::ApproxEq;
+use ::matrix::{Mat3, Mat4};
+use ::parse::{self, ParseError};
+use ::util;
+use ::vector::{Vec3, VecTrait, VecTraitF};
+
+use std::convert::From;
+use std::f64::consts;
+use std::fmt;
+use std::ops::{Mul, MulAssign, Neg};
+use std::str::FromStr;
+
+/*===============================================================================================*/
+/*------QUAT STRUCT------------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// The generic `Quat` struct.
+///
+/// It represents a rotation in 3D space. Rotations follow the right-hand rule, <br>
+/// and `lhs * rhs` applies `rhs` first, then `lhs`.
+#[cfg_attr (feature = "serde_serialize", derive (Deserialize, Serialize))]
+#[derive (Copy, Clone, Debug, PartialEq)]
+pub struct Quat<T> where
+    T: Default + Float {
+
+    // Public
+    /// The x-component of the vector part.
+    pub x: T,
+    /// The y-component of the vector part.
+    pub y: T,
+    /// The z-component of the vector part.
+    pub z: T,
+    /// The scalar part.
+    pub w: T,
+}
+
+// Predefined Quat types
+/// `Quat<f32>`
+pub type Quatf = Quat<f32>;
+
+/*===============================================================================================*/
+/*------CONSTRUCTORS-----------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Quat<T> where
+    T: Default + Float {
+
+    /// Returns a new `Quat` instance from its raw components.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::quaternion::Quat;
+    /// let quat = Quat::<f32>::new (0, 0, 0, 1);
+    /// ```
+    pub fn new<C> (x: C, y: C, z: C, w: C) -> Quat<T> where
+        C: Num + NumCast {
+
+        Quat {x: T::from (x).unwrap (),
+              y: T::from (y).unwrap (),
+              z: T::from (z).unwrap (),
+              w: T::from (w).unwrap ()}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a quaternion representing no rotation.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::quaternion::Quat;
+    /// let quat = Quat::<f32>::identity ();
+    /// ```
+    pub fn identity () -> Quat<T> {
+        Quat::new (0, 0, 0, 1)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a quaternion rotating by an angle around an axis.
+    ///
+    /// The axis does not need to be normalized.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::quaternion::Quat;
+    /// # use ion_math::vector::Vec3;
+    /// let quat = Quat::<f32>::from_axis_angle (&Vec3::up (), Rad::new (1.5));
+    /// ```
+    pub fn from_axis_angle (axis: &Vec3<T>, angle: Rad<T>) -> Quat<T> {
+
+        let axis = axis.normalize ();
+        let (sin, cos) = (angle.value * half ()).sin_cos ();
+
+        Quat {x: axis.x * sin,
+              y: axis.y * sin,
+              z: axis.z * sin,
+              w: cos}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a quaternion rotating by an angle around the x-axis.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::quaternion::Quat;
+    /// let quat = Quat::<f32>::from_rotation_x (Rad::new (1.5));
+    /// ```
+    pub fn from_rotation_x (angle: Rad<T>) -> Quat<T> {
+
+        let (sin, cos) = (angle.value * half ()).sin_cos ();
+        Quat {x: sin, y: T::zero (), z: T::zero (), w: cos}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a quaternion rotating by an angle around the y-axis.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::quaternion::Quat;
+    /// let quat = Quat::<f32>::from_rotation_y (Rad::new (1.5));
+    /// ```
+    pub fn from_rotation_y (angle: Rad<T>) -> Quat<T> {
+
+        let (sin, cos) = (angle.value * half ()).sin_cos ();
+        Quat {x: T::zero (), y: sin, z: T::zero (), w: cos}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a quaternion rotating by an angle around the z-axis.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::quaternion::Quat;
+    /// let quat = Quat::<f32>::from_rotation_z (Rad::new (1.5));
+    /// ```
+    pub fn from_rotation_z (angle: Rad<T>) -> Quat<T> {
+
+        let (sin, cos) = (angle.value * half ()).sin_cos ();
+        Quat {x: T::zero (), y: T::zero (), z: sin, w: cos}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the shortest rotation that turns one direction into another.
+    ///
+    /// The directions do not need to be normalized. When they are opposite, the <br>
+    /// rotation is half a turn around an axis perpendicular to `from`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::quaternion::Quat;
+    /// # use ion_math::vector::Vec3;
+    /// # fn main () {
+    /// let quat = Quat::<f64>::from_rotation_arc (&Vec3::forward (), &Vec3::right ());
+    /// assert_approx_eq! (quat * Vec3::forward (), Vec3::right ());
+    ///
+    /// let quat = Quat::<f64>::from_rotation_arc (&Vec3::up (), &Vec3::new (0, -2, 0));
+    /// assert_approx_eq! (quat * Vec3::up (), Vec3::down ());
+    /// # }
+    /// ```
+    pub fn from_rotation_arc (from: &Vec3<T>, to: &Vec3<T>) -> Quat<T> {
+
+        let (from, to) = (from.normalize (), to.normalize ());
+        let dot = from.dot (&to);
+
+        // Opposite directions have no unique axis, so pick one perpendicular to `from`
+        if dot < T::epsilon ().sqrt () - T::one () {
+
+            let mut axis = Vec3::right ().cross (&from);
+
+            if axis.length () < T::epsilon ().sqrt () {
+                axis = Vec3::up ().cross (&from);
+            }
+
+            return Quat::from_axis_angle (&axis, Rad::new (T::from (consts::PI).unwrap ()));
+        }
+
+        let axis = from.cross (&to);
+
+        Quat {x: axis.x,
+              y: axis.y,
+              z: axis.z,
+              w: T::one () + dot}.normalize ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a rotation that turns `Vec3::forward ()` towards a direction, <br>
+    /// keeping `Vec3::up ()` as close to an up direction as possible.
+    ///
+    /// When both directions are parallel, the shortest rotation is used instead.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::quaternion::Quat;
+    /// # use ion_math::vector::Vec3;
+    /// # fn main () {
+    /// let quat = Quat::<f64>::look_rotation (&Vec3::left (), &Vec3::up ());
+    ///
+    /// assert_approx_eq! (quat * Vec3::forward (), Vec3::left ());
+    /// assert_approx_eq! (quat * Vec3::up (), Vec3::up ());
+    /// # }
+    /// ```
+    pub fn look_rotation (forward: &Vec3<T>, up: &Vec3<T>) -> Quat<T> {
+        Quat::from (&Mat3::look_rotation (forward, up))
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> From<&'a Mat3<T>> for Quat<T> where
+    T: Default + Float {
+
+    /// Converts a rotation matrix to a quaternion.
+    ///
+    /// The matrix must be orthonormal.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::matrix::Mat3;
+    /// # use ion_math::quaternion::Quat;
+    /// # fn main () {
+    /// let quat = Quat::<f64>::from_rotation_y (Rad::new (2.0));
+    /// let mat = Mat3::from (&quat);
+    ///
+    /// assert_approx_eq! (Quat::from (&mat), quat);
+    /// # }
+    /// ```
+    fn from (mat: &Mat3<T>) -> Quat<T> {
+
+        from_rotation_matrix ([[mat[(0, 0)], mat[(0, 1)], mat[(0, 2)]],
+                               [mat[(1, 0)], mat[(1, 1)], mat[(1, 2)]],
+                               [mat[(2, 0)], mat[(2, 1)], mat[(2, 2)]]])
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> From<&'a Mat4<T>> for Quat<T> where
+    T: Default + Float {
+
+    /// Converts the rotation part of a transformation matrix to a quaternion.
+    ///
+    /// The upper-left 3x3 part of the matrix must be orthonormal.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::matrix::Mat4;
+    /// # use ion_math::quaternion::Quat;
+    /// # fn main () {
+    /// let quat = Quat::<f64>::from_rotation_z (Rad::new (-0.5));
+    /// let mat = Mat4::from (&quat);
+    ///
+    /// assert_approx_eq! (Quat::from (&mat), quat);
+    /// # }
+    /// ```
+    fn from (mat: &Mat4<T>) -> Quat<T> {
+
+        from_rotation_matrix ([[mat[(0, 0)], mat[(0, 1)], mat[(0, 2)]],
+                               [mat[(1, 0)], mat[(1, 1)], mat[(1, 2)]],
+                               [mat[(2, 0)], mat[(2, 1)], mat[(2, 2)]]])
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> From<&'a EulerAngles<T>> for Quat<T> where
+    T: Default + Float {
+
+    /// Converts Euler angles to a quaternion, following their rotation order.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::{EulerAngles, EulerOrder, Rad};
+    /// # use ion_math::quaternion::Quat;
+    /// # fn main () {
+    /// let euler = EulerAngles::<f64>::new (Rad::new (0.5), Rad::new (0.0), Rad::new (0.0),
+    ///                                      EulerOrder::IntrinsicXYZ);
+    ///
+    /// assert_approx_eq! (Quat::from (&euler), Quat::from_rotation_x (Rad::new (0.5)));
+    /// # }
+    /// ```
+    fn from (euler: &EulerAngles<T>) -> Quat<T> {
+
+        let rotations = euler.extrinsic_rotations ();
+        let mut quat = Quat::identity ();
+
+        // Each rotation around a fixed axis is applied on the left
+        for &(axis, angle) in rotations.iter () {
+
+            quat = match axis {
+
+                0 => Quat::from_rotation_x (angle) * quat,
+                1 => Quat::from_rotation_y (angle) * quat,
+                _ => Quat::from_rotation_z (angle) * quat,
+            };
+        }
+
+        quat
+    }
+}
+
+/*===============================================================================================*/
+/*------OPERATORS--------------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Mul for Quat<T> where
+    T: Default + Float {
+
+    type Output = Quat<T>;
+
+    fn mul (self, rhs: Quat<T>) -> Quat<T> {
+
+        Quat {x: self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+              y: self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+              z: self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+              w: self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Mul<&'a Quat<T>> for Quat<T> where
+    T: Default + Float {
+
+    type Output = Quat<T>;
+
+    fn mul (self, rhs: &Quat<T>) -> Quat<T> {
+
+        Quat {x: self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+              y: self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+              z: self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+              w: self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Mul<Quat<T>> for &'a Quat<T> where
+    T: Default + Float {
+
+    type Output = Quat<T>;
+
+    fn mul (self, rhs: Quat<T>) -> Quat<T> {
+
+        Quat {x: self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+              y: self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+              z: self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+              w: self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> Mul<&'a Quat<T>> for &'b Quat<T> where
+    T: Default + Float {
+
+    type Output = Quat<T>;
+
+    fn mul (self, rhs: &Quat<T>) -> Quat<T> {
+
+        Quat {x: self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+              y: self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+              z: self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+              w: self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> MulAssign for Quat<T> where
+    T: Default + Float {
+
+    fn mul_assign (&mut self, rhs: Quat<T>) {
+        *self = *self * rhs;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> MulAssign<&'a Quat<T>> for Quat<T> where
+    T: Default + Float {
+
+    fn mul_assign (&mut self, rhs: &Quat<T>) {
+        *self = *self * rhs;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Mul<Vec3<T>> for Quat<T> where
+    T: Default + Float {
+
+    type Output = Vec3<T>;
+
+    fn mul (self, rhs: Vec3<T>) -> Vec3<T> {
+
+        // v' = v + w * t + u × t, where u is the vector part and t = 2 * (u × v)
+        let u = Vec3 {x: self.x, y: self.y, z: self.z};
+        let t = u.cross (&rhs) * (T::one () + T::one ());
+
+        rhs + t * self.w + u.cross (&t)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Mul<&'a Vec3<T>> for Quat<T> where
+    T: Default + Float {
+
+    type Output = Vec3<T>;
+
+    fn mul (self, rhs: &Vec3<T>) -> Vec3<T> {
+
+        // v' = v + w * t + u × t, where u is the vector part and t = 2 * (u × v)
+        let u = Vec3 {x: self.x, y: self.y, z: self.z};
+        let t = u.cross (rhs) * (T::one () + T::one ());
+
+        *rhs + t * self.w + u.cross (&t)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Mul<Vec3<T>> for &'a Quat<T> where
+    T: Default + Float {
+
+    type Output = Vec3<T>;
+
+    fn mul (self, rhs: Vec3<T>) -> Vec3<T> {
+
+        // v' = v + w * t + u × t, where u is the vector part and t = 2 * (u × v)
+        let u = Vec3 {x: self.x, y: self.y, z: self.z};
+        let t = u.cross (&rhs) * (T::one () + T::one ());
+
+        rhs + t * self.w + u.cross (&t)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> Mul<&'a Vec3<T>> for &'b Quat<T> where
+    T: Default + Float {
+
+    type Output = Vec3<T>;
+
+    fn mul (self, rhs: &Vec3<T>) -> Vec3<T> {
+
+        // v' = v + w * t + u × t, where u is the vector part and t = 2 * (u × v)
+        let u = Vec3 {x: self.x, y: self.y, z: self.z};
+        let t = u.cross (rhs) * (T::one () + T::one ());
+
+        *rhs + t * self.w + u.cross (&t)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Neg for Quat<T> where
+    T: Default + Float {
+
+    type Output = Quat<T>;
+
+    fn neg (self) -> Quat<T> {
+        Quat {x: -self.x, y: -self.y, z: -self.z, w: -self.w}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Neg for &'a Quat<T> where
+    T: Default + Float {
+
+    type Output = Quat<T>;
+
+    fn neg (self) -> Quat<T> {
+        Quat {x: -self.x, y: -self.y, z: -self.z, w: -self.w}
+    }
+}
+
+/*===============================================================================================*/
+/*------TRAIT IMPLEMENTATIONS--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Default for Quat<T> where
+    T: Default + Float {
+
+    /// Returns the identity quaternion.
+    fn default () -> Quat<T> {
+        Quat::identity ()
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> ApproxEq for Quat<T> where
+    T: ApproxEq + Default + Float {
+
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon () -> T::Epsilon {
+        T::default_epsilon ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_relative () -> T::Epsilon {
+        T::default_max_relative ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_ulps () -> u32 {
+        T::default_max_ulps ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn abs_diff_eq (&self, other: &Quat<T>, epsilon: T::Epsilon) -> bool {
+
+        self.x.abs_diff_eq (&other.x, epsilon) &&
+        self.y.abs_diff_eq (&other.y, epsilon) &&
+        self.z.abs_diff_eq (&other.z, epsilon) &&
+        self.w.abs_diff_eq (&other.w, epsilon)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn relative_eq (&self, other: &Quat<T>, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+
+        self.x.relative_eq (&other.x, epsilon, max_relative) &&
+        self.y.relative_eq (&other.y, epsilon, max_relative) &&
+        self.z.relative_eq (&other.z, epsilon, max_relative) &&
+        self.w.relative_eq (&other.w, epsilon, max_relative)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn ulps_eq (&self, other: &Quat<T>, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+
+        self.x.ulps_eq (&other.x, epsilon, max_ulps) &&
+        self.y.ulps_eq (&other.y, epsilon, max_ulps) &&
+        self.z.ulps_eq (&other.z, epsilon, max_ulps) &&
+        self.w.ulps_eq (&other.w, epsilon, max_ulps)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> fmt::Display for Quat<T> where
+    T: Default + Float + fmt::Display {
+
+    /// Formats the quaternion as `(x, y, z, w)`, honouring the precision flag.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::quaternion::Quat;
+    /// let quat = Quat::<f32>::identity ();
+    /// assert_eq! (format! ("{:.1}", quat), "(0.0, 0.0, 0.0, 1.0)");
+    /// ```
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        parse::fmt_components (f, &[self.x, self.y, self.z, self.w])
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> FromStr for Quat<T> where
+    T: Default + Float + FromStr {
+
+    type Err = ParseError;
+
+    /// Parses a quaternion from a string such as `(0, 0, 0, 1)`, in x, y, z, w order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::quaternion::Quat;
+    /// let quat: Quat<f32> = "(0, 0, 0, 1)".parse ().unwrap ();
+    /// assert_eq! (quat, Quat::identity ());
+    /// ```
+    fn from_str (s: &str) -> Result<Quat<T>, ParseError> {
+
+        let c = parse::parse_components (s, 4)?;
+        Ok (Quat {x: c[0], y: c[1], z: c[2], w: c[3]})
+    }
+}
+
+/*===============================================================================================*/
+/*------PUBLIC METHODS---------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Quat<T> where
+    T: Default + Float {
+
+    /// Returns the dot product of two quaternions.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::quaternion::Quat;
+    /// let quat = Quat::<f32>::identity ();
+    /// assert_eq! (quat.dot (&quat), 1.0);
+    /// ```
+    pub fn dot (&self, rhs: &Quat<T>) -> T {
+
+        (self.x * rhs.x) +
+        (self.y * rhs.y) +
+        (self.z * rhs.z) +
+        (self.w * rhs.w)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the length of a quaternion.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::quaternion::Quat;
+    /// let quat = Quat::<f32>::new (0, 3, 0, 4);
+    /// assert_eq! (quat.length (), 5.0);
+    /// ```
+    pub fn length (&self) -> T {
+        self.dot (self).sqrt ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a quaternion with a length of 1.
+    ///
+    /// A zero quaternion is returned as the identity.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::quaternion::Quat;
+    /// let quat = Quat::<f32>::new (0, 3, 0, 4).normalize ();
+    /// assert_eq! (quat, Quat::new (0.0, 0.6, 0.0, 0.8));
+    /// ```
+    pub fn normalize (&self) -> Quat<T> {
+
+        let length = self.length ();
+
+        if length == T::zero () {
+            return Quat::identity ();
+        }
+
+        Quat {x: self.x / length,
+              y: self.y / length,
+              z: self.z / length,
+              w: self.w / length}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the conjugate of a quaternion.
+    ///
+    /// For a unit quaternion this is the opposite rotation.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::quaternion::Quat;
+    /// let quat = Quat::<f32>::new (1, 2, 3, 4).conjugate ();
+    /// assert_eq! (quat, Quat::new (-1, -2, -3, 4));
+    /// ```
+    pub fn conjugate (&self) -> Quat<T> {
+        Quat {x: -self.x, y: -self.y, z: -self.z, w: self.w}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the inverse of a quaternion, such that `q * q.inverse ()` is the identity.
+    ///
+    /// The components are not finite if the quaternion has a length of zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::quaternion::Quat;
+    /// # fn main () {
+    /// let quat = Quat::<f64>::new (1, 2, 3, 4);
+    /// assert_approx_eq! (quat * quat.inverse (), Quat::identity ());
+    /// # }
+    /// ```
+    pub fn inverse (&self) -> Quat<T> {
+
+        let length_squared = self.dot (self);
+        let conjugate = self.conjugate ();
+
+        Quat {x: conjugate.x / length_squared,
+              y: conjugate.y / length_squared,
+              z: conjugate.z / length_squared,
+              w: conjugate.w / length_squared}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the rotation axis and angle of a unit quaternion.
+    ///
+    /// The angle is in the range [0, π]. The identity returns the x-axis and an angle of zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::quaternion::Quat;
+    /// # use ion_math::vector::Vec3;
+    /// # fn main () {
+    /// let quat = Quat::<f64>::from_axis_angle (&Vec3::new (0, 0, -1), Rad::new (0.5));
+    /// let (axis, angle) = quat.to_axis_angle ();
+    ///
+    /// assert_approx_eq! (axis, Vec3::new (0.0, 0.0, -1.0));
+    /// assert_approx_eq! (angle, Rad::new (0.5));
+    /// # }
+    /// ```
+    pub fn to_axis_angle (&self) -> (Vec3<T>, Rad<T>) {
+
+        // Keep the angle in [0, π] by picking the hemisphere with a positive w
+        let quat = if self.w < T::zero () {-self.normalize ()} else {self.normalize ()};
+        let sin = Vec3 {x: quat.x, y: quat.y, z: quat.z}.length ();
+
+        if sin <= T::epsilon () {
+            return (Vec3::new (1, 0, 0), Rad::new (0));
+        }
+
+        (Vec3 {x: quat.x / sin, y: quat.y / sin, z: quat.z / sin},
+         Rad::new (sin.atan2 (quat.w) * (T::one () + T::one ())))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Splits a unit quaternion into a swing and a twist around an axis, such that <br>
+    /// `swing * twist` is the original rotation.
+    ///
+    /// The twist rotates around the axis, and the swing rotates the axis to its final direction. <br>
+    /// The axis does not need to be normalized.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::quaternion::Quat;
+    /// # use ion_math::vector::Vec3;
+    /// # fn main () {
+    /// let swing = Quat::<f64>::from_rotation_x (Rad::new (0.5));
+    /// let twist = Quat::from_rotation_y (Rad::new (1.2));
+    ///
+    /// let (s, t) = (swing * twist).swing_twist (&Vec3::up ());
+    ///
+    /// assert_approx_eq! (s, swing);
+    /// assert_approx_eq! (t, twist);
+    /// # }
+    /// ```
+    pub fn swing_twist (&self, axis: &Vec3<T>) -> (Quat<T>, Quat<T>) {
+
+        let axis = axis.normalize ();
+        let projection = axis * (self.x * axis.x + self.y * axis.y + self.z * axis.z);
+        let twist = Quat {x: projection.x, y: projection.y, z: projection.z, w: self.w};
+
+        // A half turn swing leaves no rotation around the axis
+        if twist.length () < T::epsilon () {
+            return (*self, Quat::identity ());
+        }
+
+        let twist = twist.normalize ();
+        (self * twist.conjugate (), twist)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Limits the swing and twist of a unit quaternion around an axis.
+    ///
+    /// The swing angle is limited to `max_swing`, and the signed twist angle <br>
+    /// to the range [`min_twist`, `max_twist`] within [-π, π].
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::quaternion::Quat;
+    /// # use ion_math::vector::Vec3;
+    /// # fn main () {
+    /// let quat = Quat::<f64>::from_rotation_x (Rad::new (1.0)) * Quat::from_rotation_y (Rad::new (-2.0));
+    /// let clamped = quat.clamp_swing_twist (&Vec3::up (), Rad::new (0.5), Rad::new (-1.0), Rad::new (1.0));
+    ///
+    /// assert_approx_eq! (clamped, Quat::from_rotation_x (Rad::new (0.5)) *
+    ///                             Quat::from_rotation_y (Rad::new (-1.0)));
+    /// # }
+    /// ```
+    pub fn clamp_swing_twist (&self,
+                              axis: &Vec3<T>,
+                              max_swing: Rad<T>,
+                              min_twist: Rad<T>,
+                              max_twist: Rad<T>) -> Quat<T> {
+
+        let (swing, twist) = self.swing_twist (axis);
+        let two = T::one () + T::one ();
+
+        // Keep both parts in the w >= 0 hemisphere, so their angles are in [-π, π]
+        let swing = if swing.w < T::zero () {-swing} else {swing};
+        let twist = if twist.w < T::zero () {-twist} else {twist};
+
+        let swing_axis = Vec3 {x: swing.x, y: swing.y, z: swing.z};
+        let swing_angle = swing_axis.length ().atan2 (swing.w) * two;
+
+        let swing = if swing_angle > max_swing.value {
+            Quat::from_axis_angle (&swing_axis, max_swing)
+        } else {
+            swing
+        };
+
+        let axis = axis.normalize ();
+        let twist_angle = (twist.x * axis.x + twist.y * axis.y + twist.z * axis.z).atan2 (twist.w) * two;
+        let twist_angle = util::clamp (twist_angle, min_twist.value, max_twist.value);
+
+        swing * Quat::from_axis_angle (&axis, Rad::new (twist_angle))
+    }
+
+/*===============================================================================================*/
+/*------PUBLIC STATIC METHODS--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Spherically interpolates between two unit quaternions along the shortest path.
+    ///
+    /// The rotation speed is constant over the interpolation.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::quaternion::Quat;
+    /// # fn main () {
+    /// let start = Quat::<f64>::identity ();
+    /// let end = Quat::from_rotation_x (Rad::new (2.0));
+    ///
+    /// let quat = Quat::slerp (&start, &end, 0.25);
+    ///
+    /// assert_approx_eq! (quat, Quat::from_rotation_x (Rad::new (0.5)));
+    /// # }
+    /// ```
+    pub fn slerp (start: &Quat<T>, end: &Quat<T>, percentage: T) -> Quat<T> {
+
+        let (end, cos) = shortest_path (start, end);
+
+        // Fall back to nlerp when the angle is too small to divide by its sine
+        if cos > T::from (0.9995).unwrap () {
+            return Quat::nlerp (start, &end, percentage);
+        }
+
+        let angle = cos.acos ();
+        let sin = angle.sin ();
+        let start_weight = ((T::one () - percentage) * angle).sin () / sin;
+        let end_weight = (percentage * angle).sin () / sin;
+
+        Quat {x: start.x * start_weight + end.x * end_weight,
+              y: start.y * start_weight + end.y * end_weight,
+              z: start.z * start_weight + end.z * end_weight,
+              w: start.w * start_weight + end.w * end_weight}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Linearly interpolates between two unit quaternions along the shortest path, <br>
+    /// then normalizes the result.
+    ///
+    /// This is cheaper than `slerp`, but the rotation speed is not constant.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::quaternion::Quat;
+    /// let start = Quat::<f32>::identity ();
+    /// let end = Quat::from_rotation_y (Rad::new (1.0));
+    ///
+    /// let quat = Quat::nlerp (&start, &end, 0.5);
+    /// ```
+    pub fn nlerp (start: &Quat<T>, end: &Quat<T>, percentage: T) -> Quat<T> {
+
+        let (end, _) = shortest_path (start, end);
+        let start_weight = T::one () - percentage;
+
+        Quat {x: start.x * start_weight + end.x * percentage,
+              y: start.y * start_weight + end.y * percentage,
+              z: start.z * start_weight + end.z * percentage,
+              w: start.w * start_weight + end.w * percentage}.normalize ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Interpolates between two unit quaternions with spherical quadrangle interpolation.
+    ///
+    /// The control points are computed with `squad_control_point`. Interpolating each pair <br>
+    /// of keyframes this way gives a curve with a continuous angular velocity.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::quaternion::Quat;
+    /// # fn main () {
+    /// let keys = [Quat::<f64>::identity (),
+    ///             Quat::from_rotation_x (Rad::new (1.0)),
+    ///             Quat::from_rotation_y (Rad::new (1.0)),
+    ///             Quat::from_rotation_z (Rad::new (1.0))];
+    ///
+    /// let start_control = Quat::squad_control_point (&keys[0], &keys[1], &keys[2]);
+    /// let end_control = Quat::squad_control_point (&keys[1], &keys[2], &keys[3]);
+    ///
+    /// let quat = Quat::squad (&keys[1], &start_control, &end_control, &keys[2], 0.0);
+    /// assert_approx_eq! (quat, keys[1]);
+    ///
+    /// let quat = Quat::squad (&keys[1], &start_control, &end_control, &keys[2], 1.0);
+    /// assert_approx_eq! (quat, keys[2]);
+    /// # }
+    /// ```
+    pub fn squad (start: &Quat<T>,
+                  start_control: &Quat<T>,
+                  end_control: &Quat<T>,
+                  end: &Quat<T>,
+                  percentage: T) -> Quat<T> {
+
+        let two = T::one () + T::one ();
+
+        Quat::slerp (&Quat::slerp (start, end, percentage),
+                     &Quat::slerp (start_control, end_control, percentage),
+                     two * percentage * (T::one () - percentage))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the squad control point of a keyframe, from its neighbouring keyframes.
+    ///
+    /// For the first and last keyframes of a track, pass the keyframe itself as <br>
+    /// the missing neighbour.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::quaternion::Quat;
+    /// # fn main () {
+    /// let previous = Quat::<f64>::from_rotation_y (Rad::new (0.5));
+    /// let current = Quat::from_rotation_y (Rad::new (1.0));
+    /// let next = Quat::from_rotation_y (Rad::new (1.5));
+    ///
+    /// // Evenly spaced keyframes around one axis need no correction
+    /// assert_approx_eq! (Quat::squad_control_point (&previous, &current, &next), current);
+    /// # }
+    /// ```
+    pub fn squad_control_point (previous: &Quat<T>, current: &Quat<T>, next: &Quat<T>) -> Quat<T> {
+
+        let (previous, _) = shortest_path (current, previous);
+        let (next, _) = shortest_path (current, next);
+
+        let inverse = current.conjugate ();
+        let tangent = (log (&(inverse * next)) + log (&(inverse * previous))) *
+                      -T::from (0.25).unwrap ();
+
+        current * exp (&tangent)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Interpolates between two unit quaternions with a cubic Hermite curve, <br>
+    /// matching an angular velocity at each end.
+    ///
+    /// The angular velocities are in world space, in radians per unit of `percentage`. <br>
+    /// Multiply velocities in radians per second by the duration of the segment. <br>
+    /// The curve follows the shortest path between the two rotations.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate ion_math;
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::quaternion::Quat;
+    /// # use ion_math::vector::Vec3;
+    /// # fn main () {
+    /// let start = Quat::<f64>::identity ();
+    /// let end = Quat::from_rotation_z (Rad::new (1.0));
+    /// let velocity = Vec3::new (0, 0, 1);
+    ///
+    /// // A constant angular velocity that matches the keyframes gives a uniform rotation
+    /// let quat = Quat::hermite (&start, &velocity, &end, &velocity, 0.25);
+    /// assert_approx_eq! (quat, Quat::from_rotation_z (Rad::new (0.25)));
+    /// # }
+    /// ```
+    pub fn hermite (start: &Quat<T>,
+                    start_velocity: &Vec3<T>,
+                    end: &Quat<T>,
+                    end_velocity: &Vec3<T>,
+                    percentage: T) -> Quat<T> {
+
+        let (end, _) = shortest_path (start, end);
+        let one = T::one ();
+        let three = T::from (3).unwrap ();
+        let sixth = one / T::from (6).unwrap ();
+
+        // Cumulative form of Kim, Kim and Shin, with the velocities moved to local space
+        let first = start.conjugate () * start_velocity * sixth;
+        let third = end.conjugate () * end_velocity * sixth;
+        let middle = log (&(exp (&-first) * start.conjugate () * end * exp (&-third)));
+
+        let inverse = one - percentage;
+        let squared = percentage * percentage;
+
+        start * exp (&(first * (one - inverse * inverse * inverse))) *
+                exp (&(middle * (three * squared - (one + one) * squared * percentage))) *
+                exp (&(third * (squared * percentage)))
+    }
+}
+
+/*===============================================================================================*/
+/*------PRIVATE FUNCTIONS------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// Returns one half in the given float type.
+fn half<T> () -> T where
+    T: Float {
+
+    T::from (0.5).unwrap ()
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+// Returns `end`, negated if needed so it lies in the same hemisphere as `start`,
+// along with the dot product of the two.
+fn shortest_path<T> (start: &Quat<T>, end: &Quat<T>) -> (Quat<T>, T) where
+    T: Default + Float {
+
+    let cos = start.dot (end);
+
+    if cos < T::zero () {(-*end, -cos)} else {(*end, cos)}
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+// Converts a row-major orthonormal rotation matrix to a quaternion.
+fn from_rotation_matrix<T> (m: [[T; 3]; 3]) -> Quat<T> where
+    T: Default + Float {
+
+    let one = T::one ();
+    let two = one + one;
+    let trace = m[0][0] + m[1][1] + m[2][2];
+
+    // Pick the largest of w, x, y and z to divide by, for numerical stability
+    if trace > T::zero () {
+
+        let s = (trace + one).sqrt () * two;
+
+        Quat {x: (m[2][1] - m[1][2]) / s,
+              y: (m[0][2] - m[2][0]) / s,
+              z: (m[1][0] - m[0][1]) / s,
+              w: s / (two * two)}
+
+    } else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+
+        let s = (one + m[0][0] - m[1][1] - m[2][2]).sqrt () * two;
+
+        Quat {x: s / (two * two),
+              y: (m[0][1] + m[1][0]) / s,
+              z: (m[0][2] + m[2][0]) / s,
+              w: (m[2][1] - m[1][2]) / s}
+
+    } else if m[1][1] > m[2][2] {
+
+        let s = (one + m[1][1] - m[0][0] - m[2][2]).sqrt () * two;
+
+        Quat {x: (m[0][1] + m[1][0]) / s,
+              y: s / (two * two),
+              z: (m[1][2] + m[2][1]) / s,
+              w: (m[0][2] - m[2][0]) / s}
+
+    } else {
+
+        let s = (one + m[2][2] - m[0][0] - m[1][1]).sqrt () * two;
+
+        Quat {x: (m[0][2] + m[2][0]) / s,
+              y: (m[1][2] + m[2][1]) / s,
+              z: s / (two * two),
+              w: (m[1][0] - m[0][1]) / s}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+// Returns the exponential of a pure quaternion, given as its vector part.
+fn exp<T> (vector: &Vec3<T>) -> Quat<T> where
+    T: Default + Float {
+
+    let angle = vector.length ();
+
+    if angle <= T::epsilon () {
+        return Quat {x: vector.x, y: vector.y, z: vector.z, w: T::one ()}.normalize ();
+    }
+
+    let (sin, cos) = angle.sin_cos ();
+    let scale = sin / angle;
+
+    Quat {x: vector.x * scale,
+          y: vector.y * scale,
+          z: vector.z * scale,
+          w: cos}
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+// Returns the logarithm of a unit quaternion, as the vector part of a pure quaternion.
+fn log<T> (quat: &Quat<T>) -> Vec3<T> where
+    T: Default + Float {
+
+    let vector = Vec3 {x: quat.x, y: quat.y, z: quat.z};
+    let sin = vector.length ();
+
+    if sin <= T::epsilon () {
+        return vector;
+    }
+
+    vector * (sin.atan2 (quat.w) / sin)
+}
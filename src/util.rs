@@ -22,7 +22,7 @@
 extern crate num_traits;
 
 // Module imports
-use self::num_traits::{Num, NumCast};
+use self::num_traits::{Float, Num, NumCast};
 
 /*===============================================================================================*/
 /*------PUBLIC FUNCTIONS-------------------------------------------------------------------------*/
@@ -42,12 +42,36 @@ use self::num_traits::{Num, NumCast};
 pub fn clamp<T> (value: T, min: T, max: T) -> T where
     T: Copy + Num + PartialOrd {
 
-    debug_assert! (min < max, "Min cannot be greater than max.");
+    debug_assert! (min <= max, "Min cannot be greater than max.");
     if value < min {min} else if value > max {max} else {value}
 }
 
 /*-----------------------------------------------------------------------------------------------*/
 
+/// Moves a value towards a target with exponential decay.
+///
+/// Unlike `lerp` with a constant percentage, the result does not depend on the frame rate. <br>
+/// Larger rates converge faster. The remaining distance halves every `ln (2) / rate` seconds.
+///
+/// # Examples
+/// ```
+/// # use ion_math::util;
+/// let mut position = 0.0_f32;
+///
+/// for _ in 0..60 {
+///     position = util::damp (position, 10.0, 5.0, 1.0 / 60.0);
+/// }
+///
+/// assert! (position > 9.9 && position < 10.0);
+/// ```
+pub fn damp<T> (current: T, target: T, rate: T, delta_time: T) -> T where
+    T: Float {
+
+    current + (target - current) * (T::one () - (-rate * delta_time).exp ())
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
 /// Linearly interpolates between two values.
 ///
 /// # Examples
@@ -90,3 +114,75 @@ pub fn min<T> (lhs: T, rhs: T) -> T where
 
     if lhs < rhs {lhs} else {rhs}
 }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+/// Moves a value towards a target by at most `max_delta`, without overshooting.
+///
+/// # Examples
+/// ```
+/// # use ion_math::util;
+/// assert_eq! (util::move_towards (0.0, 10.0, 3.0), 3.0);
+/// assert_eq! (util::move_towards (9.0, 10.0, 3.0), 10.0);
+/// ```
+pub fn move_towards<T> (current: T, target: T, max_delta: T) -> T where
+    T: Float {
+
+    let delta = target - current;
+
+    if delta.abs () <= max_delta {
+        return target;
+    }
+
+    current + delta.signum () * max_delta
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+/// Smoothly moves a value towards a target using a critically damped spring.
+///
+/// `velocity` holds the current speed and is updated on each call. `smooth_time` is <br>
+/// roughly the time taken to reach the target. The result never overshoots the target.
+///
+/// # Examples
+/// ```
+/// # use ion_math::util;
+/// let mut position = 0.0_f32;
+/// let mut velocity = 0.0;
+///
+/// for _ in 0..120 {
+///     position = util::smooth_damp (position, 10.0, &mut velocity, 0.3, 1.0 / 60.0);
+/// }
+///
+/// assert! ((position - 10.0).abs () < 0.01);
+/// ```
+pub fn smooth_damp<T> (current:     T,
+                       target:      T,
+                       velocity:    &mut T,
+                       smooth_time: T,
+                       delta_time:  T) -> T where
+    T: Float {
+
+    // Approximation of exp (-omega * delta_time) from Game Programming Gems 4, chapter 1.10
+    let smooth_time = smooth_time.max (T::from (0.0001).unwrap ());
+    let omega = T::from (2.0).unwrap () / smooth_time;
+    let x = omega * delta_time;
+    let exp = T::one () / (T::one () + x +
+                           T::from (0.48).unwrap () * x * x +
+                           T::from (0.235).unwrap () * x * x * x);
+
+    let change = current - target;
+    let temp = (*velocity + change * omega) * delta_time;
+
+    *velocity = (*velocity - temp * omega) * exp;
+    let output = target + (change + temp) * exp;
+
+    // Stop at the target instead of overshooting it
+    if (target > current) == (output > target) {
+
+        *velocity = T::zero ();
+        return target;
+    }
+
+    output
+}
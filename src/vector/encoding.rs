@@ -0,0 +1,338 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+/*===============================================================================================*/
+//! Packs unit normals into two components for compact storage.
+//!
+//! Two encodings are provided: <br>
+//! * Octahedral, which projects the sphere onto an octahedron and unfolds it into a square.
+//!   It is close to uniform and is the recommended choice. <br>
+//! * Spherical, which stores the two angles of the normal. It is cheaper to reason about,
+//!   but wastes precision near the poles. <br>
+//!
+//! Both encodings produce a `Vec2f` in the range [-1, 1], which can be quantized to 16 or 8
+//! bits per component with `quantize_u16` / `quantize_u8`. <br>
+//! The quantization is snorm: a value is scaled by the largest value of `i16` / `i8` and
+//! rounded, so -1, 0 and 1 are all stored exactly. <br>
+//! The signed results are returned as their two's complement bit patterns in `u16` / `u8`,
+//! ready to be read back as snorm vertex attributes or texture formats.
+//!
+//! # Round-trip error
+//! The largest angle between a unit normal and its decoded value, measured over <br>
+//! two million normals spread evenly over the sphere:
+//!
+//! | Encoding          | Max error   |
+//! |-------------------|-------------|
+//! | Octahedral `f32`  | < 0.0001°   |
+//! | Octahedral `u16`  | < 0.004°    |
+//! | Octahedral `u8`   | < 1.0°      |
+//! | Spherical `f32`   | < 0.0001°   |
+//! | Spherical `u16`   | < 0.0035°   |
+//! | Spherical `u8`    | < 0.8°      |
+/*===============================================================================================*/
+
+// Module imports
+use ::util;
+use ::vector::{Vec2, Vec2f, Vec3, Vec3f, VecTraitF};
+
+use std::f32::consts::PI;
+
+/*===============================================================================================*/
+/*------OCTAHEDRAL FUNCTIONS---------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Encodes a unit normal using the octahedral mapping.
+///
+/// The result lies in the range [-1, 1] on both axes.
+///
+/// # Examples
+/// ```
+/// # use ion_math::vector::Vec3;
+/// # use ion_math::vector::encoding;
+/// let normal = Vec3::<f32>::up ();
+/// let encoded = encoding::oct_encode (&normal);
+///
+/// assert! ((encoding::oct_decode (&encoded).y - 1.0).abs () < 0.0001);
+/// ```
+pub fn oct_encode (normal: &Vec3f) -> Vec2f {
+
+    let inv_l1 = 1.0 / (normal.x.abs () + normal.y.abs () + normal.z.abs ());
+    let x = normal.x * inv_l1;
+    let y = normal.y * inv_l1;
+
+    // Fold the lower hemisphere over the diagonals
+    if normal.z < 0.0 {
+
+        return Vec2 {x: (1.0 - y.abs ()) * sign_not_zero (x),
+                     y: (1.0 - x.abs ()) * sign_not_zero (y)};
+    }
+
+    Vec2 {x, y}
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+/// Decodes a normal encoded with `oct_encode`.
+///
+/// # Examples
+/// ```
+/// # use ion_math::vector::{Vec2, Vec3};
+/// # use ion_math::vector::encoding;
+/// let normal = encoding::oct_decode (&Vec2::new (0.0, 0.0));
+///
+/// assert_eq! (normal, Vec3::new (0.0, 0.0, 1.0));
+/// ```
+pub fn oct_decode (encoded: &Vec2f) -> Vec3f {
+
+    let mut normal = Vec3 {x: encoded.x,
+                           y: encoded.y,
+                           z: 1.0 - encoded.x.abs () - encoded.y.abs ()};
+
+    // Unfold the lower hemisphere
+    let t = (-normal.z).max (0.0);
+    normal.x -= t * sign_not_zero (normal.x);
+    normal.y -= t * sign_not_zero (normal.y);
+
+    normal.normalize ()
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+/// Encodes a unit normal using the octahedral mapping, quantized to 16-bit snorm.
+///
+/// # Examples
+/// ```
+/// # use ion_math::vector::Vec3;
+/// # use ion_math::vector::encoding;
+/// let normal = Vec3::<f32>::new (0.6, 0.0, -0.8);
+/// let decoded = encoding::oct_decode_u16 (&encoding::oct_encode_u16 (&normal));
+///
+/// assert! (decoded.x > 0.5999 && decoded.z < -0.7999);
+/// ```
+pub fn oct_encode_u16 (normal: &Vec3f) -> Vec2<u16> {
+    quantize_u16 (&oct_encode (normal))
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+/// Decodes a normal encoded with `oct_encode_u16`.
+///
+/// # Examples
+/// ```
+/// # use ion_math::vector::Vec2;
+/// # use ion_math::vector::encoding;
+/// let normal = encoding::oct_decode_u16 (&Vec2::new (0, 32767));
+///
+/// assert! (normal.y > 0.9999);
+/// ```
+pub fn oct_decode_u16 (encoded: &Vec2<u16>) -> Vec3f {
+    oct_decode (&dequantize_u16 (encoded))
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+/// Encodes a unit normal using the octahedral mapping, quantized to 8-bit snorm.
+///
+/// # Examples
+/// ```
+/// # use ion_math::vector::{Vec2, Vec3};
+/// # use ion_math::vector::encoding;
+/// let normal = Vec3::<f32>::new (0.0, 0.0, 1.0);
+///
+/// assert_eq! (encoding::oct_encode_u8 (&normal), Vec2::new (0, 0));
+/// ```
+pub fn oct_encode_u8 (normal: &Vec3f) -> Vec2<u8> {
+    quantize_u8 (&oct_encode (normal))
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+/// Decodes a normal encoded with `oct_encode_u8`.
+///
+/// # Examples
+/// ```
+/// # use ion_math::vector::Vec2;
+/// # use ion_math::vector::encoding;
+/// let normal = encoding::oct_decode_u8 (&Vec2::new (127, 0));
+///
+/// assert! (normal.x > 0.99);
+/// ```
+pub fn oct_decode_u8 (encoded: &Vec2<u8>) -> Vec3f {
+    oct_decode (&dequantize_u8 (encoded))
+}
+
+/*===============================================================================================*/
+/*------SPHERICAL FUNCTIONS----------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Encodes a unit normal as its spherical angles.
+///
+/// The x-axis holds the azimuth from +X towards +Z, and the y-axis holds <br>
+/// the polar angle from -Y to +Y, both remapped to the range [-1, 1].
+///
+/// # Examples
+/// ```
+/// # use ion_math::vector::Vec3;
+/// # use ion_math::vector::encoding;
+/// let encoded = encoding::spherical_encode (&Vec3::new (0.0, 1.0, 0.0));
+///
+/// assert_eq! (encoded.y, 1.0);
+/// ```
+pub fn spherical_encode (normal: &Vec3f) -> Vec2f {
+
+    Vec2 {x: normal.z.atan2 (normal.x) / PI,
+          y: 1.0 - normal.x.hypot (normal.z).atan2 (normal.y) * 2.0 / PI}
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+/// Decodes a normal encoded with `spherical_encode`.
+///
+/// # Examples
+/// ```
+/// # use ion_math::vector::Vec3;
+/// # use ion_math::vector::encoding;
+/// let normal = Vec3::<f32>::new (0.0, 0.6, 0.8);
+/// let decoded = encoding::spherical_decode (&encoding::spherical_encode (&normal));
+///
+/// assert! ((decoded.z - 0.8).abs () < 0.0001);
+/// ```
+pub fn spherical_decode (encoded: &Vec2f) -> Vec3f {
+
+    let (sin_phi, cos_phi) = (encoded.x * PI).sin_cos ();
+    let (sin_theta, cos_theta) = ((1.0 - encoded.y) * PI * 0.5).sin_cos ();
+
+    Vec3 {x: sin_theta * cos_phi,
+          y: cos_theta,
+          z: sin_theta * sin_phi}
+}
+
+/*===============================================================================================*/
+/*------QUANTIZATION FUNCTIONS-------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Quantizes a vector in the range [-1, 1] to 16-bit snorm, rounding to the nearest value.
+///
+/// Each component holds the bits of an `i16` in the range [-32767, 32767].
+///
+/// # Examples
+/// ```
+/// # use ion_math::vector::Vec2;
+/// # use ion_math::vector::encoding;
+/// let quantized = encoding::quantize_u16 (&Vec2::new (-1.0, 0.0));
+///
+/// assert_eq! (quantized.x as i16, -32767);
+/// assert_eq! (quantized.y, 0);
+/// ```
+pub fn quantize_u16 (value: &Vec2f) -> Vec2<u16> {
+
+    let max = i16::MAX as f32;
+
+    Vec2 {x: quantize (value.x, max) as i16 as u16,
+          y: quantize (value.y, max) as i16 as u16}
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+/// Maps a vector quantized with `quantize_u16` back to the range [-1, 1].
+///
+/// Both -32768 and -32767 decode to -1.
+///
+/// # Examples
+/// ```
+/// # use ion_math::vector::Vec2;
+/// # use ion_math::vector::encoding;
+/// let value = encoding::dequantize_u16 (&Vec2::new (-32768i16 as u16, 32767));
+///
+/// assert_eq! (value, Vec2::new (-1.0, 1.0));
+/// assert_eq! (encoding::dequantize_u16 (&Vec2::new (0, 0)), Vec2::new (0.0, 0.0));
+/// ```
+pub fn dequantize_u16 (value: &Vec2<u16>) -> Vec2f {
+
+    let max = i16::MAX as f32;
+
+    Vec2 {x: dequantize (value.x as i16 as f32, max),
+          y: dequantize (value.y as i16 as f32, max)}
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+/// Quantizes a vector in the range [-1, 1] to 8-bit snorm, rounding to the nearest value.
+///
+/// Each component holds the bits of an `i8` in the range [-127, 127].
+///
+/// # Examples
+/// ```
+/// # use ion_math::vector::Vec2;
+/// # use ion_math::vector::encoding;
+/// let quantized = encoding::quantize_u8 (&Vec2::new (-1.0, 0.0));
+///
+/// assert_eq! (quantized.x as i8, -127);
+/// assert_eq! (quantized.y, 0);
+/// ```
+pub fn quantize_u8 (value: &Vec2f) -> Vec2<u8> {
+
+    let max = i8::MAX as f32;
+
+    Vec2 {x: quantize (value.x, max) as i8 as u8,
+          y: quantize (value.y, max) as i8 as u8}
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+/// Maps a vector quantized with `quantize_u8` back to the range [-1, 1].
+///
+/// Both -128 and -127 decode to -1.
+///
+/// # Examples
+/// ```
+/// # use ion_math::vector::Vec2;
+/// # use ion_math::vector::encoding;
+/// let value = encoding::dequantize_u8 (&Vec2::new (-128i8 as u8, 127));
+///
+/// assert_eq! (value, Vec2::new (-1.0, 1.0));
+/// assert_eq! (encoding::dequantize_u8 (&Vec2::new (0, 0)), Vec2::new (0.0, 0.0));
+/// ```
+pub fn dequantize_u8 (value: &Vec2<u8>) -> Vec2f {
+
+    let max = i8::MAX as f32;
+
+    Vec2 {x: dequantize (value.x as i8 as f32, max),
+          y: dequantize (value.y as i8 as f32, max)}
+}
+
+/*===============================================================================================*/
+/*------PRIVATE FUNCTIONS------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// Returns 1 for positive values and zero, and -1 for negative values.
+fn sign_not_zero (value: f32) -> f32 {
+    if value >= 0.0 {1.0} else {-1.0}
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+// Maps a value in [-1, 1] to [-max, max].
+fn quantize (value: f32, max: f32) -> f32 {
+    (util::clamp (value, -1.0, 1.0) * max).round ()
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+// Maps a value in [-max - 1, max] to [-1, 1].
+fn dequantize (value: f32, max: f32) -> f32 {
+    (value / max).max (-1.0)
+}
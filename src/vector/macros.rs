@@ -0,0 +1,77 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+/*===============================================================================================*/
+/*------PRIVATE MACROS---------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+// Implements `From<$prim>` for a vector, setting every component to the same value.
+//
+// A blanket `From<U: Num>` impl would conflict with the array and tuple conversions,
+// so the primitive number types are listed explicitly instead.
+macro_rules! impl_vec_from_scalar {
+
+    ($vec:ident $fields:tt; $($prim:ty),+) => {$(
+        impl_vec_from_scalar! (@impl $vec $fields $prim);
+    )+};
+
+    (@impl $vec:ident {$($field:ident),+} $prim:ty) => {
+
+        impl<T> From<$prim> for $vec<T> where
+            T: Copy + Num + NumCast {
+
+            fn from (value: $prim) -> $vec<T> {
+                $vec {$($field: T::from (value).unwrap ()),+}
+            }
+        }
+    };
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+// Implements the arithmetic operators with a primitive scalar on the left hand side,
+// for both owned and borrowed vectors.
+macro_rules! impl_vec_scalar_lhs_ops {
+
+    ($vec:ident $fields:tt; $($prim:ty),+) => {$(
+        impl_vec_scalar_lhs_ops! (@op $vec $fields $prim; Add, add, +);
+        impl_vec_scalar_lhs_ops! (@op $vec $fields $prim; Sub, sub, -);
+        impl_vec_scalar_lhs_ops! (@op $vec $fields $prim; Mul, mul, *);
+        impl_vec_scalar_lhs_ops! (@op $vec $fields $prim; Div, div, /);
+        impl_vec_scalar_lhs_ops! (@op $vec $fields $prim; Rem, rem, %);
+    )+};
+
+    (@op $vec:ident {$($field:ident),+} $prim:ty; $op:ident, $func:ident, $sym:tt) => {
+
+        impl $op<$vec<$prim>> for $prim {
+
+            type Output = $vec<$prim>;
+
+            fn $func (self, rhs: $vec<$prim>) -> $vec<$prim> {
+                $vec {$($field: self $sym rhs.$field),+}
+            }
+        }
+
+        impl<'a> $op<&'a $vec<$prim>> for $prim {
+
+            type Output = $vec<$prim>;
+
+            fn $func (self, rhs: &$vec<$prim>) -> $vec<$prim> {
+                $vec {$($field: self $sym rhs.$field),+}
+            }
+        }
+    };
+}
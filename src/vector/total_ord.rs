@@ -0,0 +1,106 @@
+/*===============================================================================================*/
+// Copyright 2016 Kyle Finlay
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+/*===============================================================================================*/
+
+// Crate imports
+extern crate num_traits;
+
+// Module imports
+use self::num_traits::Float;
+use self::num_traits::float::TotalOrder;
+
+use ::vector::{Vec2, Vec3, Vec4};
+
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+/*===============================================================================================*/
+/*------TOTAL ORD STRUCT-------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+/// Wraps a float vector so it can be used as a key in sorted or hashed collections.
+///
+/// Components are compared lexicographically using the IEEE 754 total order, <br>
+/// so `-0.0` sorts before `0.0` and `NaN` values are equal to themselves.
+///
+/// # Examples
+/// ```
+/// # use ion_math::vector::{TotalOrd, Vec2};
+/// # use std::collections::BTreeSet;
+/// let mut set = BTreeSet::new ();
+///
+/// set.insert (TotalOrd (Vec2::<f32>::new (1.0, 2.0)));
+/// set.insert (TotalOrd (Vec2::<f32>::new (0.5, 3.0)));
+///
+/// assert_eq! (set.iter ().next ().unwrap ().0, Vec2::new (0.5, 3.0));
+/// ```
+#[derive (Copy, Clone, Debug, Default)]
+pub struct TotalOrd<V> (pub V);
+
+/*===============================================================================================*/
+/*------TRAIT IMPLEMENTATIONS--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+macro_rules! impl_total_ord {
+
+    ($($vec:ident),+) => {$(
+
+        impl<T> PartialEq for TotalOrd<$vec<T>> where
+            T: Float + TotalOrder {
+
+            fn eq (&self, other: &TotalOrd<$vec<T>>) -> bool {
+                self.cmp (other) == Ordering::Equal
+            }
+        }
+
+        impl<T> Eq for TotalOrd<$vec<T>> where
+            T: Float + TotalOrder {}
+
+        impl<T> PartialOrd for TotalOrd<$vec<T>> where
+            T: Float + TotalOrder {
+
+            fn partial_cmp (&self, other: &TotalOrd<$vec<T>>) -> Option<Ordering> {
+                Some (self.cmp (other))
+            }
+        }
+
+        impl<T> Ord for TotalOrd<$vec<T>> where
+            T: Float + TotalOrder {
+
+            fn cmp (&self, other: &TotalOrd<$vec<T>>) -> Ordering {
+
+                self.0.iter ()
+                      .zip (other.0.iter ())
+                      .map (|(lhs, rhs)| lhs.total_cmp (rhs))
+                      .find (|ordering| *ordering != Ordering::Equal)
+                      .unwrap_or (Ordering::Equal)
+            }
+        }
+
+        impl<T> Hash for TotalOrd<$vec<T>> where
+            T: Float + TotalOrder {
+
+            fn hash<H: Hasher> (&self, state: &mut H) {
+
+                // Values that are equal in the total order have identical bits
+                for value in self.0.iter () {
+                    value.integer_decode ().hash (state);
+                }
+            }
+        }
+    )+};
+}
+
+impl_total_ord! (Vec2, Vec3, Vec4);
@@ -20,15 +20,29 @@ extern crate num_traits;
 // Module imports
 use self::num_traits::{Float, Num, NumCast};
 
+use ::angle::Rad;
+use ::approx::ApproxEq;
+use ::parse::{self, ParseError};
 use ::util;
 use ::vector::{Vec3, Vec4, VecTrait, VecTraitF};
 
+use std::{array, slice};
 use std::convert::From;
-use std::ops::{Add,   AddAssign,
-               Sub,   SubAssign,
-               Mul,   MulAssign,
-               Div,   DivAssign,
-               Index, IndexMut};
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{Add,    AddAssign,
+               Sub,    SubAssign,
+               Mul,    MulAssign,
+               Div,    DivAssign,
+               Rem,    RemAssign,
+               BitAnd, BitAndAssign,
+               BitOr,  BitOrAssign,
+               BitXor, BitXorAssign,
+               Shl,    ShlAssign,
+               Shr,    ShrAssign,
+               Index,  IndexMut,
+               Neg,    Not};
+use std::str::FromStr;
 
 /*===============================================================================================*/
 /*------VEC2 STRUCT------------------------------------------------------------------------------*/
@@ -40,7 +54,8 @@ use std::ops::{Add,   AddAssign,
 /// UV coordinates, etc. <br>
 /// It can accept any number as a value.
 #[cfg_attr (feature = "serde_serialize", derive (Deserialize, Serialize))]
-#[derive (Copy, Clone, Debug, Default, PartialEq)]
+#[derive (Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr (C)]
 pub struct Vec2<T> where
     T: Copy + Num + NumCast {
 
@@ -83,16 +98,7 @@ impl<T> Vec2<T> where
 
 /*-----------------------------------------------------------------------------------------------*/
 
-impl<T, U> From<U> for Vec2<T> where
-    T: Copy + Num + NumCast,
-    U: Copy + Num + NumCast {
-
-    fn from (value: U) -> Vec2<T> {
-
-        Vec2::new (value,
-                   value)
-    }
-}
+impl_vec_from_scalar! (Vec2 {x, y}; i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
 
 /*-----------------------------------------------------------------------------------------------*/
 
@@ -133,6 +139,46 @@ impl<'a, T, U> From<&'a Vec4<U>> for Vec2<T> where
     }
 }
 
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> From<[T; 2]> for Vec2<T> where
+    T: Copy + Num + NumCast {
+
+    fn from (value: [T; 2]) -> Vec2<T> {
+        Vec2 {x: value[0], y: value[1]}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> From<(T, T)> for Vec2<T> where
+    T: Copy + Num + NumCast {
+
+    fn from (value: (T, T)) -> Vec2<T> {
+        Vec2 {x: value.0, y: value.1}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> From<Vec2<T>> for [T; 2] where
+    T: Copy + Num + NumCast {
+
+    fn from (value: Vec2<T>) -> [T; 2] {
+        [value.x, value.y]
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> From<Vec2<T>> for (T, T) where
+    T: Copy + Num + NumCast {
+
+    fn from (value: Vec2<T>) -> (T, T) {
+        (value.x, value.y)
+    }
+}
+
 /*===============================================================================================*/
 /*------OPERATORS--------------------------------------------------------------------------------*/
 /*===============================================================================================*/
@@ -569,214 +615,1528 @@ impl<T> DivAssign<T> for Vec2<T> where
 
 /*-----------------------------------------------------------------------------------------------*/
 
-impl<T> Index<u8> for Vec2<T> where
+impl<T> Neg for Vec2<T> where
+    T: Copy + Neg<Output = T> + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn neg (self) -> Vec2<T> {
+
+        Vec2::new (-self.x,
+                   -self.y)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Neg for &'a Vec2<T> where
+    T: Copy + Neg<Output = T> + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn neg (self) -> Vec2<T> {
+
+        Vec2::new (-self.x,
+                   -self.y)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Rem for Vec2<T> where
     T: Copy + Num + NumCast {
 
-    type Output = T;
+    type Output = Vec2<T>;
 
-    fn index (&self, index: u8) -> &T {
+    fn rem (self, rhs: Vec2<T>) -> Vec2<T> {
 
-        match index {
+        Vec2::new (self.x % rhs.x,
+                   self.y % rhs.y)
+    }
+}
 
-            0 => &self.x,
-            1 => &self.y,
-            _ => unreachable! ("Index out of range for Vec2")
-        }
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Rem<&'a Vec2<T>> for Vec2<T> where
+    T: Copy + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn rem (self, rhs: &Vec2<T>) -> Vec2<T> {
+
+        Vec2::new (self.x % rhs.x,
+                   self.y % rhs.y)
     }
 }
 
 /*-----------------------------------------------------------------------------------------------*/
 
-impl<T> IndexMut<u8> for Vec2<T> where
+impl<'a, T> Rem<Vec2<T>> for &'a Vec2<T> where
     T: Copy + Num + NumCast {
 
-    fn index_mut (&mut self, index: u8) -> &mut T {
+    type Output = Vec2<T>;
 
-        match index {
+    fn rem (self, rhs: Vec2<T>) -> Vec2<T> {
 
-            0 => &mut self.x,
-            1 => &mut self.y,
-            _ => unreachable! ("Index out of range for Vec2")
-        }
+        Vec2::new (self.x % rhs.x,
+                   self.y % rhs.y)
     }
 }
 
-/*===============================================================================================*/
-/*------TRAIT IMPLEMENTATIONS--------------------------------------------------------------------*/
-/*===============================================================================================*/
+/*-----------------------------------------------------------------------------------------------*/
 
-impl<T> VecTrait for Vec2<T> where
-    T: Copy + Default + Num + NumCast + PartialOrd {
+impl<'a, 'b, T> Rem<&'a Vec2<T>> for &'b Vec2<T> where
+    T: Copy + Num + NumCast {
 
-    type ValType = T;
+    type Output = Vec2<T>;
 
-    fn lerp (start: &Vec2<T>, end: &Vec2<T>, percentage: f32) -> Vec2<T> {
+    fn rem (self, rhs: &Vec2<T>) -> Vec2<T> {
 
-        Vec2::new (util::lerp (start.x, end.x, percentage),
-                   util::lerp (start.y, end.y, percentage))
+        Vec2::new (self.x % rhs.x,
+                   self.y % rhs.y)
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    fn max (lhs: &Vec2<T>, rhs: &Vec2<T>) -> Vec2<T> {
+impl<T> Rem<T> for Vec2<T> where
+    T: Copy + Num + NumCast {
 
-        Vec2::new (util::max (lhs.x, rhs.x),
-                   util::max (lhs.y, rhs.y))
+    type Output = Vec2<T>;
+
+    fn rem (self, rhs: T) -> Vec2<T> {
+
+        Vec2::new (self.x % rhs,
+                   self.y % rhs)
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    fn min (lhs: &Vec2<T>, rhs: &Vec2<T>) -> Vec2<T> {
+impl<'a, T> Rem<T> for &'a Vec2<T> where
+    T: Copy + Num + NumCast {
 
-        Vec2::new (util::min (lhs.x, rhs.x),
-                   util::min (lhs.y, rhs.y))
+    type Output = Vec2<T>;
+
+    fn rem (self, rhs: T) -> Vec2<T> {
+
+        Vec2::new (self.x % rhs,
+                   self.y % rhs)
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    fn clamp (&self, min: &Vec2<T>, max: &Vec2<T>) -> Vec2<T> {
+impl<T> RemAssign for Vec2<T> where
+    T: Copy + Num + NumCast {
 
-        Vec2::new (util::clamp (self.x, min.x, max.x),
-                   util::clamp (self.y, min.y, max.y))
+    fn rem_assign (&mut self, rhs: Vec2<T>) {
+
+        self.x = self.x % rhs.x;
+        self.y = self.y % rhs.y;
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    fn dot (&self, rhs: &Vec2<T>) -> T {
+impl<T> RemAssign<T> for Vec2<T> where
+    T: Copy + Num + NumCast {
 
-        (self.x * rhs.x) +
-        (self.y * rhs.y)
+    fn rem_assign (&mut self, rhs: T) {
+
+        self.x = self.x % rhs;
+        self.y = self.y % rhs;
     }
 }
 
 /*-----------------------------------------------------------------------------------------------*/
 
-impl<T> VecTraitF for Vec2<T> where
-    T: Default + Float {
+impl<T> BitAnd for Vec2<T> where
+    T: BitAnd<Output = T> + Copy + Num + NumCast {
 
-    type ValTypeF = T;
+    type Output = Vec2<T>;
 
-    /// Returns the distance between two vectors.
-    ///
-    /// # Examples
-    /// ```
-    /// # use ion_math::vector::{Vec2, VecTraitF};
-    /// let vec01 = Vec2::<f32>::new (1.0, 3.0);
-    /// let vec02 = Vec2::<f32>::new (4.0, 9.0);
-    ///
-    /// let distance = vec01.distance (&vec02);
-    /// ```
-    fn distance (&self, rhs: &Vec2<T>) -> T {
-        (self - rhs).length ()
+    fn bitand (self, rhs: Vec2<T>) -> Vec2<T> {
+
+        Vec2::new (self.x & rhs.x,
+                   self.y & rhs.y)
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    /// Returns the length of a vector.
-    ///
-    /// # Examples
-    /// ```
-    /// # use ion_math::vector::{Vec2, VecTraitF};
-    /// let vec = Vec2::<f32>::new (1.0, 3.0);
-    /// let vec_length = vec.length ();
-    /// ```
-    fn length (&self) -> T {
+impl<'a, T> BitAnd<&'a Vec2<T>> for Vec2<T> where
+    T: BitAnd<Output = T> + Copy + Num + NumCast {
 
-        (self.x * self.x +
-         self.y * self.y).sqrt ()
+    type Output = Vec2<T>;
+
+    fn bitand (self, rhs: &Vec2<T>) -> Vec2<T> {
+
+        Vec2::new (self.x & rhs.x,
+                   self.y & rhs.y)
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    /// Returns a normalized vector.
-    ///
-    /// # Examples
-    /// ```
-    /// # use ion_math::vector::{Vec2, VecTraitF};
-    /// let vec = Vec2::<f32>::new (3.0, 9.0);
-    /// let vec_normalized = vec.normalize ();
-    /// ```
-    fn normalize (&self) -> Vec2<T> {
+impl<'a, T> BitAnd<Vec2<T>> for &'a Vec2<T> where
+    T: BitAnd<Output = T> + Copy + Num + NumCast {
 
-        let length = self.length ();
+    type Output = Vec2<T>;
 
-        if length != T::zero () {
+    fn bitand (self, rhs: Vec2<T>) -> Vec2<T> {
 
-            return Vec2::new (self.x / length,
-                              self.y / length);
-        }
+        Vec2::new (self.x & rhs.x,
+                   self.y & rhs.y)
+    }
+}
 
-        Vec2::zero ()
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> BitAnd<&'a Vec2<T>> for &'b Vec2<T> where
+    T: BitAnd<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn bitand (self, rhs: &Vec2<T>) -> Vec2<T> {
+
+        Vec2::new (self.x & rhs.x,
+                   self.y & rhs.y)
     }
 }
 
-/*===============================================================================================*/
-/*------PUBLIC STATIC METHODS--------------------------------------------------------------------*/
-/*===============================================================================================*/
+/*-----------------------------------------------------------------------------------------------*/
 
-impl<T> Vec2<T> where
-    T: Copy + Num + NumCast {
+impl<T> BitAnd<T> for Vec2<T> where
+    T: BitAnd<Output = T> + Copy + Num + NumCast {
 
-    /// Returns a `Vec2<T>` with a value of (0, 1).
-    ///
-    /// # Examples
-    /// ```
-    /// # use ion_math::vector::Vec2;
-    /// let vec = Vec2::<f32>::up ();
-    /// ```
-    pub fn up () -> Vec2<T> {
-        Vec2::new (0, 1)
+    type Output = Vec2<T>;
+
+    fn bitand (self, rhs: T) -> Vec2<T> {
+
+        Vec2::new (self.x & rhs,
+                   self.y & rhs)
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    /// Returns a `Vec2<T>` with a value of (0, -1).
-    ///
-    /// # Examples
-    /// ```
-    /// # use ion_math::vector::Vec2;
-    /// let vec = Vec2::<f32>::down ();
-    /// ```
-    pub fn down () -> Vec2<T> {
-        Vec2::new (0, -1)
+impl<'a, T> BitAnd<T> for &'a Vec2<T> where
+    T: BitAnd<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn bitand (self, rhs: T) -> Vec2<T> {
+
+        Vec2::new (self.x & rhs,
+                   self.y & rhs)
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    /// Returns a `Vec2<T>` with a value of (0, -1).
-    ///
-    /// # Examples
-    /// ```
-    /// # use ion_math::vector::Vec2;
-    /// let vec = Vec2::<f32>::left ();
-    /// ```
-    pub fn left () -> Vec2<T> {
-        Vec2::new (-1, 0)
+impl<T> BitAndAssign for Vec2<T> where
+    T: BitAnd<Output = T> + Copy + Num + NumCast {
+
+    fn bitand_assign (&mut self, rhs: Vec2<T>) {
+
+        self.x = self.x & rhs.x;
+        self.y = self.y & rhs.y;
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    /// Returns a `Vec2<T>` with a value of (0, 1).
-    ///
-    /// # Examples
-    /// ```
-    /// # use ion_math::vector::Vec2;
-    /// let vec = Vec2::<f32>::right ();
-    /// ```
-    pub fn right () -> Vec2<T> {
-        Vec2::new (1, 0)
+impl<T> BitAndAssign<T> for Vec2<T> where
+    T: BitAnd<Output = T> + Copy + Num + NumCast {
+
+    fn bitand_assign (&mut self, rhs: T) {
+
+        self.x = self.x & rhs;
+        self.y = self.y & rhs;
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    /// Returns a `Vec2<T>` with a value of (0, 0).
-    ///
-    /// # Examples
-    /// ```
-    /// # use ion_math::vector::Vec2;
-    /// let vec = Vec2::<f32>::zero ();
-    /// ```
-    pub fn zero () -> Vec2<T> {
-        Vec2::from (0)
+impl<T> BitOr for Vec2<T> where
+    T: BitOr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn bitor (self, rhs: Vec2<T>) -> Vec2<T> {
+
+        Vec2::new (self.x | rhs.x,
+                   self.y | rhs.y)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> BitOr<&'a Vec2<T>> for Vec2<T> where
+    T: BitOr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn bitor (self, rhs: &Vec2<T>) -> Vec2<T> {
+
+        Vec2::new (self.x | rhs.x,
+                   self.y | rhs.y)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> BitOr<Vec2<T>> for &'a Vec2<T> where
+    T: BitOr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn bitor (self, rhs: Vec2<T>) -> Vec2<T> {
+
+        Vec2::new (self.x | rhs.x,
+                   self.y | rhs.y)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> BitOr<&'a Vec2<T>> for &'b Vec2<T> where
+    T: BitOr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn bitor (self, rhs: &Vec2<T>) -> Vec2<T> {
+
+        Vec2::new (self.x | rhs.x,
+                   self.y | rhs.y)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> BitOr<T> for Vec2<T> where
+    T: BitOr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn bitor (self, rhs: T) -> Vec2<T> {
+
+        Vec2::new (self.x | rhs,
+                   self.y | rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> BitOr<T> for &'a Vec2<T> where
+    T: BitOr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn bitor (self, rhs: T) -> Vec2<T> {
+
+        Vec2::new (self.x | rhs,
+                   self.y | rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> BitOrAssign for Vec2<T> where
+    T: BitOr<Output = T> + Copy + Num + NumCast {
+
+    fn bitor_assign (&mut self, rhs: Vec2<T>) {
+
+        self.x = self.x | rhs.x;
+        self.y = self.y | rhs.y;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> BitOrAssign<T> for Vec2<T> where
+    T: BitOr<Output = T> + Copy + Num + NumCast {
+
+    fn bitor_assign (&mut self, rhs: T) {
+
+        self.x = self.x | rhs;
+        self.y = self.y | rhs;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> BitXor for Vec2<T> where
+    T: BitXor<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn bitxor (self, rhs: Vec2<T>) -> Vec2<T> {
+
+        Vec2::new (self.x ^ rhs.x,
+                   self.y ^ rhs.y)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> BitXor<&'a Vec2<T>> for Vec2<T> where
+    T: BitXor<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn bitxor (self, rhs: &Vec2<T>) -> Vec2<T> {
+
+        Vec2::new (self.x ^ rhs.x,
+                   self.y ^ rhs.y)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> BitXor<Vec2<T>> for &'a Vec2<T> where
+    T: BitXor<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn bitxor (self, rhs: Vec2<T>) -> Vec2<T> {
+
+        Vec2::new (self.x ^ rhs.x,
+                   self.y ^ rhs.y)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> BitXor<&'a Vec2<T>> for &'b Vec2<T> where
+    T: BitXor<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn bitxor (self, rhs: &Vec2<T>) -> Vec2<T> {
+
+        Vec2::new (self.x ^ rhs.x,
+                   self.y ^ rhs.y)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> BitXor<T> for Vec2<T> where
+    T: BitXor<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn bitxor (self, rhs: T) -> Vec2<T> {
+
+        Vec2::new (self.x ^ rhs,
+                   self.y ^ rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> BitXor<T> for &'a Vec2<T> where
+    T: BitXor<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn bitxor (self, rhs: T) -> Vec2<T> {
+
+        Vec2::new (self.x ^ rhs,
+                   self.y ^ rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> BitXorAssign for Vec2<T> where
+    T: BitXor<Output = T> + Copy + Num + NumCast {
+
+    fn bitxor_assign (&mut self, rhs: Vec2<T>) {
+
+        self.x = self.x ^ rhs.x;
+        self.y = self.y ^ rhs.y;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> BitXorAssign<T> for Vec2<T> where
+    T: BitXor<Output = T> + Copy + Num + NumCast {
+
+    fn bitxor_assign (&mut self, rhs: T) {
+
+        self.x = self.x ^ rhs;
+        self.y = self.y ^ rhs;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Shl for Vec2<T> where
+    T: Shl<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn shl (self, rhs: Vec2<T>) -> Vec2<T> {
+
+        Vec2::new (self.x << rhs.x,
+                   self.y << rhs.y)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Shl<&'a Vec2<T>> for Vec2<T> where
+    T: Shl<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn shl (self, rhs: &Vec2<T>) -> Vec2<T> {
+
+        Vec2::new (self.x << rhs.x,
+                   self.y << rhs.y)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Shl<Vec2<T>> for &'a Vec2<T> where
+    T: Shl<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn shl (self, rhs: Vec2<T>) -> Vec2<T> {
+
+        Vec2::new (self.x << rhs.x,
+                   self.y << rhs.y)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> Shl<&'a Vec2<T>> for &'b Vec2<T> where
+    T: Shl<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn shl (self, rhs: &Vec2<T>) -> Vec2<T> {
+
+        Vec2::new (self.x << rhs.x,
+                   self.y << rhs.y)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Shl<T> for Vec2<T> where
+    T: Shl<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn shl (self, rhs: T) -> Vec2<T> {
+
+        Vec2::new (self.x << rhs,
+                   self.y << rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Shl<T> for &'a Vec2<T> where
+    T: Shl<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn shl (self, rhs: T) -> Vec2<T> {
+
+        Vec2::new (self.x << rhs,
+                   self.y << rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> ShlAssign for Vec2<T> where
+    T: Shl<Output = T> + Copy + Num + NumCast {
+
+    fn shl_assign (&mut self, rhs: Vec2<T>) {
+
+        self.x = self.x << rhs.x;
+        self.y = self.y << rhs.y;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> ShlAssign<T> for Vec2<T> where
+    T: Shl<Output = T> + Copy + Num + NumCast {
+
+    fn shl_assign (&mut self, rhs: T) {
+
+        self.x = self.x << rhs;
+        self.y = self.y << rhs;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Shr for Vec2<T> where
+    T: Shr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn shr (self, rhs: Vec2<T>) -> Vec2<T> {
+
+        Vec2::new (self.x >> rhs.x,
+                   self.y >> rhs.y)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Shr<&'a Vec2<T>> for Vec2<T> where
+    T: Shr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn shr (self, rhs: &Vec2<T>) -> Vec2<T> {
+
+        Vec2::new (self.x >> rhs.x,
+                   self.y >> rhs.y)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Shr<Vec2<T>> for &'a Vec2<T> where
+    T: Shr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn shr (self, rhs: Vec2<T>) -> Vec2<T> {
+
+        Vec2::new (self.x >> rhs.x,
+                   self.y >> rhs.y)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> Shr<&'a Vec2<T>> for &'b Vec2<T> where
+    T: Shr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn shr (self, rhs: &Vec2<T>) -> Vec2<T> {
+
+        Vec2::new (self.x >> rhs.x,
+                   self.y >> rhs.y)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Shr<T> for Vec2<T> where
+    T: Shr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn shr (self, rhs: T) -> Vec2<T> {
+
+        Vec2::new (self.x >> rhs,
+                   self.y >> rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Shr<T> for &'a Vec2<T> where
+    T: Shr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn shr (self, rhs: T) -> Vec2<T> {
+
+        Vec2::new (self.x >> rhs,
+                   self.y >> rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> ShrAssign for Vec2<T> where
+    T: Shr<Output = T> + Copy + Num + NumCast {
+
+    fn shr_assign (&mut self, rhs: Vec2<T>) {
+
+        self.x = self.x >> rhs.x;
+        self.y = self.y >> rhs.y;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> ShrAssign<T> for Vec2<T> where
+    T: Shr<Output = T> + Copy + Num + NumCast {
+
+    fn shr_assign (&mut self, rhs: T) {
+
+        self.x = self.x >> rhs;
+        self.y = self.y >> rhs;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Not for Vec2<T> where
+    T: Copy + Not<Output = T> + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn not (self) -> Vec2<T> {
+
+        Vec2::new (!self.x,
+                   !self.y)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Not for &'a Vec2<T> where
+    T: Copy + Not<Output = T> + Num + NumCast {
+
+    type Output = Vec2<T>;
+
+    fn not (self) -> Vec2<T> {
+
+        Vec2::new (!self.x,
+                   !self.y)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl_vec_scalar_lhs_ops! (Vec2 {x, y}; i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Index<u8> for Vec2<T> where
+    T: Copy + Num + NumCast {
+
+    type Output = T;
+
+    fn index (&self, index: u8) -> &T {
+
+        match index {
+
+            0 => &self.x,
+            1 => &self.y,
+            _ => unreachable! ("Index out of range for Vec2")
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> IndexMut<u8> for Vec2<T> where
+    T: Copy + Num + NumCast {
+
+    fn index_mut (&mut self, index: u8) -> &mut T {
+
+        match index {
+
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => unreachable! ("Index out of range for Vec2")
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Index<usize> for Vec2<T> where
+    T: Copy + Num + NumCast {
+
+    type Output = T;
+
+    fn index (&self, index: usize) -> &T {
+        self.get (index).expect ("Index out of range for Vec2")
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> IndexMut<usize> for Vec2<T> where
+    T: Copy + Num + NumCast {
+
+    fn index_mut (&mut self, index: usize) -> &mut T {
+        self.get_mut (index).expect ("Index out of range for Vec2")
+    }
+}
+
+/*===============================================================================================*/
+/*------TRAIT IMPLEMENTATIONS--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> VecTrait for Vec2<T> where
+    T: Copy + Default + Num + NumCast + PartialOrd {
+
+    type ValType = T;
+
+    fn lerp (start: &Vec2<T>, end: &Vec2<T>, percentage: f32) -> Vec2<T> {
+
+        Vec2::new (util::lerp (start.x, end.x, percentage),
+                   util::lerp (start.y, end.y, percentage))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn max (lhs: &Vec2<T>, rhs: &Vec2<T>) -> Vec2<T> {
+
+        Vec2::new (util::max (lhs.x, rhs.x),
+                   util::max (lhs.y, rhs.y))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn min (lhs: &Vec2<T>, rhs: &Vec2<T>) -> Vec2<T> {
+
+        Vec2::new (util::min (lhs.x, rhs.x),
+                   util::min (lhs.y, rhs.y))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn clamp (&self, min: &Vec2<T>, max: &Vec2<T>) -> Vec2<T> {
+
+        Vec2::new (util::clamp (self.x, min.x, max.x),
+                   util::clamp (self.y, min.y, max.y))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn dot (&self, rhs: &Vec2<T>) -> T {
+
+        (self.x * rhs.x) +
+        (self.y * rhs.y)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> VecTraitF for Vec2<T> where
+    T: Default + Float {
+
+    type ValTypeF = T;
+
+    /// Returns the distance between two vectors.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::{Vec2, VecTraitF};
+    /// let vec01 = Vec2::<f32>::new (1.0, 3.0);
+    /// let vec02 = Vec2::<f32>::new (4.0, 9.0);
+    ///
+    /// let distance = vec01.distance (&vec02);
+    /// ```
+    fn distance (&self, rhs: &Vec2<T>) -> T {
+        (self - rhs).length ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the length of a vector.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::{Vec2, VecTraitF};
+    /// let vec = Vec2::<f32>::new (1.0, 3.0);
+    /// let vec_length = vec.length ();
+    /// ```
+    fn length (&self) -> T {
+
+        (self.x * self.x +
+         self.y * self.y).sqrt ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a normalized vector.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::{Vec2, VecTraitF};
+    /// let vec = Vec2::<f32>::new (3.0, 9.0);
+    /// let vec_normalized = vec.normalize ();
+    /// ```
+    fn normalize (&self) -> Vec2<T> {
+
+        let length = self.length ();
+
+        if length != T::zero () {
+
+            return Vec2::new (self.x / length,
+                              self.y / length);
+        }
+
+        Vec2::zero ()
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> ApproxEq for Vec2<T> where
+    T: ApproxEq + Copy + Num + NumCast {
+
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon () -> T::Epsilon {
+        T::default_epsilon ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_relative () -> T::Epsilon {
+        T::default_max_relative ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_ulps () -> u32 {
+        T::default_max_ulps ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn abs_diff_eq (&self, other: &Vec2<T>, epsilon: T::Epsilon) -> bool {
+
+        self.x.abs_diff_eq (&other.x, epsilon) &&
+        self.y.abs_diff_eq (&other.y, epsilon)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn relative_eq (&self, other: &Vec2<T>, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+
+        self.x.relative_eq (&other.x, epsilon, max_relative) &&
+        self.y.relative_eq (&other.y, epsilon, max_relative)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn ulps_eq (&self, other: &Vec2<T>, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+
+        self.x.ulps_eq (&other.x, epsilon, max_ulps) &&
+        self.y.ulps_eq (&other.y, epsilon, max_ulps)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> AsRef<[T]> for Vec2<T> where
+    T: Copy + Num + NumCast {
+
+    fn as_ref (&self) -> &[T] {
+
+        // The struct is repr (C) and only holds `T` values, so the components are contiguous
+        unsafe {slice::from_raw_parts (self as *const Vec2<T> as *const T, 2)}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> AsMut<[T]> for Vec2<T> where
+    T: Copy + Num + NumCast {
+
+    fn as_mut (&mut self) -> &mut [T] {
+
+        // The struct is repr (C) and only holds `T` values, so the components are contiguous
+        unsafe {slice::from_raw_parts_mut (self as *mut Vec2<T> as *mut T, 2)}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> IntoIterator for Vec2<T> where
+    T: Copy + Num + NumCast {
+
+    type Item     = T;
+    type IntoIter = array::IntoIter<T, 2>;
+
+    fn into_iter (self) -> array::IntoIter<T, 2> {
+        IntoIterator::into_iter (<[T; 2]>::from (self))
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> IntoIterator for &'a Vec2<T> where
+    T: Copy + Num + NumCast {
+
+    type Item     = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter (self) -> slice::Iter<'a, T> {
+        self.iter ()
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> IntoIterator for &'a mut Vec2<T> where
+    T: Copy + Num + NumCast {
+
+    type Item     = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter (self) -> slice::IterMut<'a, T> {
+        self.iter_mut ()
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Sum for Vec2<T> where
+    T: Copy + Num + NumCast {
+
+    fn sum<I> (iter: I) -> Vec2<T> where
+        I: Iterator<Item = Vec2<T>> {
+
+        iter.fold (Vec2::from (0), |acc, vec| acc + vec)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Sum<&'a Vec2<T>> for Vec2<T> where
+    T: Copy + Num + NumCast {
+
+    fn sum<I> (iter: I) -> Vec2<T> where
+        I: Iterator<Item = &'a Vec2<T>> {
+
+        iter.fold (Vec2::from (0), |acc, vec| acc + vec)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Product for Vec2<T> where
+    T: Copy + Num + NumCast {
+
+    fn product<I> (iter: I) -> Vec2<T> where
+        I: Iterator<Item = Vec2<T>> {
+
+        iter.fold (Vec2::from (1), |acc, vec| acc * vec)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Product<&'a Vec2<T>> for Vec2<T> where
+    T: Copy + Num + NumCast {
+
+    fn product<I> (iter: I) -> Vec2<T> where
+        I: Iterator<Item = &'a Vec2<T>> {
+
+        iter.fold (Vec2::from (1), |acc, vec| acc * vec)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> fmt::Display for Vec2<T> where
+    T: Copy + fmt::Display + Num + NumCast {
+
+    /// Formats the vector as `(x, y)`, honouring the precision flag.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec2;
+    /// let vec = Vec2::<f32>::new (1, 2);
+    /// assert_eq! (format! ("{:.1}", vec), "(1.0, 2.0)");
+    /// ```
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        parse::fmt_components (f, self.as_ref ())
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> FromStr for Vec2<T> where
+    T: Copy + FromStr + Num + NumCast {
+
+    type Err = ParseError;
+
+    /// Parses a vector from a string such as `(1, 2)` or `1 2`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec2;
+    /// let vec: Vec2<f32> = "(1, 2)".parse ().unwrap ();
+    /// assert_eq! (vec, Vec2::new (1, 2));
+    /// ```
+    fn from_str (s: &str) -> Result<Vec2<T>, ParseError> {
+
+        let c = parse::parse_components (s, 2)?;
+        Ok (Vec2 {x: c[0], y: c[1]})
+    }
+}
+
+/*===============================================================================================*/
+/*------PUBLIC METHODS---------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Vec2<T> where
+    T: Copy + Num + NumCast {
+
+    /// Returns a reference to a component, or `None` if the index is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec2;
+    /// let vec = Vec2::<f32>::new (1, 2);
+    ///
+    /// assert_eq! (vec.get (1), Some (&2.0));
+    /// assert_eq! (vec.get (2), None);
+    /// ```
+    pub fn get (&self, index: usize) -> Option<&T> {
+        self.as_ref ().get (index)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a mutable reference to a component, or `None` if the index is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec2;
+    /// let mut vec = Vec2::<f32>::new (1, 2);
+    ///
+    /// if let Some (x) = vec.get_mut (0) {
+    ///     *x = 10.0;
+    /// }
+    ///
+    /// vec[0usize] = 1.0;
+    /// assert_eq! (vec[1usize] * 2.0, 4.0);
+    /// ```
+    pub fn get_mut (&mut self, index: usize) -> Option<&mut T> {
+        self.as_mut ().get_mut (index)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns an iterator over the vector components.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec2;
+    /// let vec = Vec2::<i32>::new (1, 2);
+    /// let sum: i32 = vec.iter ().sum ();
+    /// ```
+    pub fn iter (&self) -> slice::Iter<'_, T> {
+        self.as_ref ().iter ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a mutable iterator over the vector components.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec2;
+    /// let mut vec = Vec2::<i32>::new (1, 2);
+    ///
+    /// for component in vec.iter_mut () {
+    ///     *component *= 2;
+    /// }
+    /// ```
+    pub fn iter_mut (&mut self) -> slice::IterMut<'_, T> {
+        self.as_mut ().iter_mut ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a new vector with a function applied to each component.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec2;
+    /// let vec = Vec2::<f32>::new (1, 2);
+    /// let vec_squared = vec.map (|c| c * c);
+    /// ```
+    pub fn map<U, F> (&self, mut f: F) -> Vec2<U> where
+        U: Copy + Num + NumCast,
+        F: FnMut (T) -> U {
+
+        Vec2 {x: f (self.x), y: f (self.y)}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a new vector with a function applied to each pair of components.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec2;
+    /// let vec01 = Vec2::<i32>::new (1, 2);
+    /// let vec02 = Vec2::<i32>::new (4, 5);
+    ///
+    /// let vec_max = vec01.zip_map (&vec02, |a, b| if a > b {a} else {b});
+    /// ```
+    pub fn zip_map<U, F> (&self, other: &Vec2<T>, mut f: F) -> Vec2<U> where
+        U: Copy + Num + NumCast,
+        F: FnMut (T, T) -> U {
+
+        Vec2 {x: f (self.x, other.x), y: f (self.y, other.y)}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the perpendicular dot product of two vectors.
+    ///
+    /// This is the z-component of the 3D cross product, and is positive <br>
+    /// when `rhs` is counter-clockwise from `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec2;
+    /// let vec01 = Vec2::<i32>::new (1, 0);
+    /// let vec02 = Vec2::<i32>::new (0, 1);
+    ///
+    /// assert_eq! (vec01.perp_dot (&vec02), 1);
+    /// ```
+    pub fn perp_dot (&self, rhs: &Vec2<T>) -> T {
+        (self.x * rhs.y) - (self.y * rhs.x)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the 2D cross product of two vectors.
+    ///
+    /// This is the same as `perp_dot`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec2;
+    /// let vec01 = Vec2::<f32>::new (1, 3);
+    /// let vec02 = Vec2::<f32>::new (4, 9);
+    ///
+    /// assert_eq! (vec01.cross (&vec02), -3.0);
+    /// ```
+    pub fn cross (&self, rhs: &Vec2<T>) -> T {
+        self.perp_dot (rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Vec2<T> where
+    T: Copy + Neg<Output = T> + Num + NumCast {
+
+    /// Returns the vector rotated 90 degrees counter-clockwise.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec2;
+    /// let vec = Vec2::<i32>::new (2, 1);
+    ///
+    /// assert_eq! (vec.perp (), Vec2::new (-1, 2));
+    /// ```
+    pub fn perp (&self) -> Vec2<T> {
+        Vec2 {x: -self.y, y: self.x}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+// `Ord` also provides `max`, `min` and `clamp`, which compare lexicographically.
+// These shadow them so the component-wise `VecTrait` versions keep working for integer vectors.
+impl<T> Vec2<T> where
+    T: Copy + Default + Num + NumCast + PartialOrd {
+
+    /// Returns the largest components of two vectors.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec2;
+    /// let vec = Vec2::max (&Vec2::<i32>::new (1, 5), &Vec2::new (2, 0));
+    ///
+    /// assert_eq! (vec, Vec2::new (2, 5));
+    /// ```
+    pub fn max (lhs: &Vec2<T>, rhs: &Vec2<T>) -> Vec2<T> {
+        VecTrait::max (lhs, rhs)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the smallest components of two vectors.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec2;
+    /// let vec = Vec2::min (&Vec2::<i32>::new (1, 5), &Vec2::new (2, 0));
+    ///
+    /// assert_eq! (vec, Vec2::new (1, 0));
+    /// ```
+    pub fn min (lhs: &Vec2<T>, rhs: &Vec2<T>) -> Vec2<T> {
+        VecTrait::min (lhs, rhs)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Clamps each component of a vector between two values.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec2;
+    /// let vec = Vec2::<i32>::new (1, 5).clamp (&Vec2::zero (), &Vec2::from (2));
+    ///
+    /// assert_eq! (vec, Vec2::new (1, 2));
+    /// ```
+    pub fn clamp (self, min: &Vec2<T>, max: &Vec2<T>) -> Vec2<T> {
+        VecTrait::clamp (&self, min, max)
+    }
+}
+
+/*===============================================================================================*/
+/*------PUBLIC STATIC METHODS--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Vec2<T> where
+    T: Copy + Num + NumCast {
+
+    /// Returns a `Vec2<T>` with a value of (0, 1).
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec2;
+    /// let vec = Vec2::<f32>::up ();
+    /// ```
+    pub fn up () -> Vec2<T> {
+        Vec2::new (0, 1)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a `Vec2<T>` with a value of (0, -1).
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec2;
+    /// let vec = Vec2::<f32>::down ();
+    /// ```
+    pub fn down () -> Vec2<T> {
+        Vec2::new (0, -1)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a `Vec2<T>` with a value of (0, -1).
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec2;
+    /// let vec = Vec2::<f32>::left ();
+    /// ```
+    pub fn left () -> Vec2<T> {
+        Vec2::new (-1, 0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a `Vec2<T>` with a value of (0, 1).
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec2;
+    /// let vec = Vec2::<f32>::right ();
+    /// ```
+    pub fn right () -> Vec2<T> {
+        Vec2::new (1, 0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a `Vec2<T>` with a value of (0, 0).
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec2;
+    /// let vec = Vec2::<f32>::zero ();
+    /// ```
+    pub fn zero () -> Vec2<T> {
+        Vec2::from (0)
+    }
+}
+
+/*===============================================================================================*/
+/*------PUBLIC FLOAT METHODS---------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Vec2<T> where
+    T: Default + Float {
+
+    /// Returns the polar coordinates of the vector as (radius, angle).
+    ///
+    /// The angle is measured from the positive x-axis towards the positive y-axis.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec2;
+    /// let vec = Vec2::<f32>::new (0.0, 3.0);
+    /// let (radius, angle) = vec.to_polar ();
+    ///
+    /// assert_eq! (radius, 3.0);
+    /// ```
+    pub fn to_polar (&self) -> (T, Rad<T>) {
+
+        (self.length (),
+         Rad::atan2 (self.y, self.x))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the vector rotated counter-clockwise by an angle.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::vector::Vec2;
+    /// # use std::f32::consts::PI;
+    /// let vec = Vec2::<f32>::new (1.0, 0.0).rotate (Rad::new (PI * 0.5));
+    ///
+    /// assert! (vec.x.abs () < 0.0001 && vec.y > 0.9999);
+    /// ```
+    pub fn rotate (&self, angle: Rad<T>) -> Vec2<T> {
+
+        let (sin, cos) = angle.sin_cos ();
+
+        Vec2 {x: self.x * cos - self.y * sin,
+              y: self.x * sin + self.y * cos}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the angle of the vector from the positive x-axis, in the range [-π, π].
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec2;
+    /// let angle = Vec2::<f32>::new (0.0, -2.0).to_angle ();
+    ///
+    /// assert_eq! (angle.value, -std::f32::consts::FRAC_PI_2);
+    /// ```
+    pub fn to_angle (&self) -> Rad<T> {
+        Rad::atan2 (self.y, self.x)
+    }
+
+/*===============================================================================================*/
+/*------PUBLIC STATIC FLOAT METHODS--------------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a `Vec2<T>` from polar coordinates.
+    ///
+    /// The angle is measured from the positive x-axis towards the positive y-axis.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::vector::Vec2;
+    /// let vec = Vec2::<f32>::from_polar (5.0, Rad::new (0.9));
+    /// ```
+    pub fn from_polar (radius: T, angle: Rad<T>) -> Vec2<T> {
+
+        let (sin, cos) = angle.sin_cos ();
+
+        Vec2::new (radius * cos,
+                   radius * sin)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a unit vector pointing at an angle from the positive x-axis.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::vector::Vec2;
+    /// let vec = Vec2::<f32>::from_angle (Rad::new (0.0));
+    ///
+    /// assert_eq! (vec, Vec2::new (1.0, 0.0));
+    /// ```
+    pub fn from_angle (angle: Rad<T>) -> Vec2<T> {
+
+        let (sin, cos) = angle.sin_cos ();
+
+        Vec2 {x: cos, y: sin}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Moves a vector towards a target by at most `max_distance`, without overshooting.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec2;
+    /// let current = Vec2::<f32>::new (0.0, 0.0);
+    /// let target = Vec2::<f32>::new (3.0, 4.0);
+    ///
+    /// assert_eq! (Vec2::move_towards (&current, &target, 1.0), Vec2::new (0.6, 0.8));
+    /// ```
+    pub fn move_towards (current: &Vec2<T>, target: &Vec2<T>, max_distance: T) -> Vec2<T> {
+
+        let delta = target - current;
+        let distance = delta.length ();
+
+        if distance <= max_distance || distance == T::zero () {
+            return *target;
+        }
+
+        current + delta * (max_distance / distance)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Smoothly moves a vector towards a target using a critically damped spring.
+    ///
+    /// See `util::smooth_damp` for details. Each component is damped independently.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec2;
+    /// let mut position = Vec2::<f32>::zero ();
+    /// let mut velocity = Vec2::<f32>::zero ();
+    /// let target = Vec2::<f32>::new (3.0, 4.0);
+    ///
+    /// position = Vec2::smooth_damp (&position, &target, &mut velocity, 0.3, 1.0 / 60.0);
+    /// ```
+    pub fn smooth_damp (current: &Vec2<T>, target: &Vec2<T>, velocity: &mut Vec2<T>,
+                        smooth_time: T, delta_time: T) -> Vec2<T> {
+
+        Vec2 {x: util::smooth_damp (current.x, target.x, &mut velocity.x, smooth_time, delta_time),
+              y: util::smooth_damp (current.y, target.y, &mut velocity.y, smooth_time, delta_time)}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Moves a vector towards a target with frame-rate independent exponential decay.
+    ///
+    /// See `util::damp` for details.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec2;
+    /// let position = Vec2::<f32>::zero ();
+    /// let target = Vec2::<f32>::new (3.0, 4.0);
+    ///
+    /// let position = Vec2::damp (&position, &target, 5.0, 1.0 / 60.0);
+    /// ```
+    pub fn damp (current: &Vec2<T>, target: &Vec2<T>, rate: T, delta_time: T) -> Vec2<T> {
+
+        Vec2 {x: util::damp (current.x, target.x, rate, delta_time),
+              y: util::damp (current.y, target.y, rate, delta_time)}
     }
 }
@@ -20,15 +20,29 @@ extern crate num_traits;
 // Module imports
 use self::num_traits::{Float, Num, NumCast};
 
+use ::angle::Rad;
+use ::approx::ApproxEq;
+use ::parse::{self, ParseError};
 use ::util;
 use ::vector::{Vec2, Vec4, VecTrait, VecTraitF};
 
+use std::{array, slice};
 use std::convert::From;
-use std::ops::{Add,   AddAssign,
-               Sub,   SubAssign,
-               Mul,   MulAssign,
-               Div,   DivAssign,
-               Index, IndexMut};
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{Add,    AddAssign,
+               Sub,    SubAssign,
+               Mul,    MulAssign,
+               Div,    DivAssign,
+               Rem,    RemAssign,
+               BitAnd, BitAndAssign,
+               BitOr,  BitOrAssign,
+               BitXor, BitXorAssign,
+               Shl,    ShlAssign,
+               Shr,    ShrAssign,
+               Index,  IndexMut,
+               Neg,    Not};
+use std::str::FromStr;
 
 /*===============================================================================================*/
 /*------VEC3 STRUCT------------------------------------------------------------------------------*/
@@ -39,7 +53,8 @@ use std::ops::{Add,   AddAssign,
 /// It is used for 3D transformations and graphics.
 /// It can accept any number as a value.
 #[cfg_attr (feature = "serde_serialize", derive (Deserialize, Serialize))]
-#[derive (Copy, Clone, Debug, Default, PartialEq)]
+#[derive (Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr (C)]
 pub struct Vec3<T> where
     T: Copy + Num + NumCast {
 
@@ -85,17 +100,7 @@ impl<T> Vec3<T> where
 
 /*-----------------------------------------------------------------------------------------------*/
 
-impl<T, U> From<U> for Vec3<T> where
-    T: Copy + Num + NumCast,
-    U: Copy + Num + NumCast {
-
-    fn from (value: U) -> Vec3<T> {
-
-        Vec3::new (value,
-                   value,
-                   value)
-    }
-}
+impl_vec_from_scalar! (Vec3 {x, y, z}; i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
 
 /*-----------------------------------------------------------------------------------------------*/
 
@@ -127,6 +132,7 @@ impl<'a, T, U> From<&'a Vec3<U>> for Vec3<T> where
 
 /*-----------------------------------------------------------------------------------------------*/
 
+// Discards w. Use `Vec4::perspective_divide` to convert a homogeneous point.
 impl<'a, T, U> From<&'a Vec4<U>> for Vec3<T> where
     T: Copy + Num + NumCast,
     U: Copy + Num + NumCast {
@@ -139,6 +145,46 @@ impl<'a, T, U> From<&'a Vec4<U>> for Vec3<T> where
     }
 }
 
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> From<[T; 3]> for Vec3<T> where
+    T: Copy + Num + NumCast {
+
+    fn from (value: [T; 3]) -> Vec3<T> {
+        Vec3 {x: value[0], y: value[1], z: value[2]}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> From<(T, T, T)> for Vec3<T> where
+    T: Copy + Num + NumCast {
+
+    fn from (value: (T, T, T)) -> Vec3<T> {
+        Vec3 {x: value.0, y: value.1, z: value.2}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> From<Vec3<T>> for [T; 3] where
+    T: Copy + Num + NumCast {
+
+    fn from (value: Vec3<T>) -> [T; 3] {
+        [value.x, value.y, value.z]
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> From<Vec3<T>> for (T, T, T) where
+    T: Copy + Num + NumCast {
+
+    fn from (value: Vec3<T>) -> (T, T, T) {
+        (value.x, value.y, value.z)
+    }
+}
+
 /*===============================================================================================*/
 /*------OPERATORS--------------------------------------------------------------------------------*/
 /*===============================================================================================*/
@@ -607,245 +653,1369 @@ impl<T> DivAssign<T> for Vec3<T> where
 
 /*-----------------------------------------------------------------------------------------------*/
 
-impl<T> Index<u8> for Vec3<T> where
-    T: Copy + Num + NumCast {
+impl<T> Neg for Vec3<T> where
+    T: Copy + Neg<Output = T> + Num + NumCast {
 
-    type Output = T;
+    type Output = Vec3<T>;
 
-    fn index (&self, index: u8) -> &T {
+    fn neg (self) -> Vec3<T> {
 
-        match index {
+        Vec3::new (-self.x,
+                   -self.y,
+                   -self.z)
+    }
+}
 
-            0 => &self.x,
-            1 => &self.y,
-            2 => &self.z,
-            _ => unreachable! ("Index out of range for Vec3")
-        }
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Neg for &'a Vec3<T> where
+    T: Copy + Neg<Output = T> + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn neg (self) -> Vec3<T> {
+
+        Vec3::new (-self.x,
+                   -self.y,
+                   -self.z)
     }
 }
 
 /*-----------------------------------------------------------------------------------------------*/
 
-impl<T> IndexMut<u8> for Vec3<T> where
+impl<T> Rem for Vec3<T> where
     T: Copy + Num + NumCast {
 
-    fn index_mut (&mut self, index: u8) -> &mut T {
+    type Output = Vec3<T>;
 
-        match index {
+    fn rem (self, rhs: Vec3<T>) -> Vec3<T> {
 
-            0 => &mut self.x,
-            1 => &mut self.y,
-            2 => &mut self.z,
-            _ => unreachable! ("Index out of range for Vec3")
-        }
+        Vec3::new (self.x % rhs.x,
+                   self.y % rhs.y,
+                   self.z % rhs.z)
     }
 }
 
-/*===============================================================================================*/
-/*------TRAIT IMPLEMENTATIONS--------------------------------------------------------------------*/
-/*===============================================================================================*/
+/*-----------------------------------------------------------------------------------------------*/
 
-impl<T> VecTrait for Vec3<T> where
-    T: Copy + Default + Num + NumCast + PartialOrd {
+impl<'a, T> Rem<&'a Vec3<T>> for Vec3<T> where
+    T: Copy + Num + NumCast {
 
-    type ValType = T;
+    type Output = Vec3<T>;
 
-    fn lerp (start: &Vec3<T>, end: &Vec3<T>, percentage: f32) -> Vec3<T> {
+    fn rem (self, rhs: &Vec3<T>) -> Vec3<T> {
 
-        Vec3::new (util::lerp (start.x, end.x, percentage),
-                   util::lerp (start.y, end.y, percentage),
-                   util::lerp (start.z, end.z, percentage))
+        Vec3::new (self.x % rhs.x,
+                   self.y % rhs.y,
+                   self.z % rhs.z)
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    fn max (lhs: &Vec3<T>, rhs: &Vec3<T>) -> Vec3<T> {
+impl<'a, T> Rem<Vec3<T>> for &'a Vec3<T> where
+    T: Copy + Num + NumCast {
 
-        Vec3::new (util::max (lhs.x, rhs.x),
-                   util::max (lhs.y, rhs.y),
-                   util::max (lhs.z, rhs.z))
+    type Output = Vec3<T>;
+
+    fn rem (self, rhs: Vec3<T>) -> Vec3<T> {
+
+        Vec3::new (self.x % rhs.x,
+                   self.y % rhs.y,
+                   self.z % rhs.z)
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    fn min (lhs: &Vec3<T>, rhs: &Vec3<T>) -> Vec3<T> {
+impl<'a, 'b, T> Rem<&'a Vec3<T>> for &'b Vec3<T> where
+    T: Copy + Num + NumCast {
 
-        Vec3::new (util::min (lhs.x, rhs.x),
-                   util::min (lhs.y, rhs.y),
-                   util::min (lhs.z, rhs.z))
+    type Output = Vec3<T>;
+
+    fn rem (self, rhs: &Vec3<T>) -> Vec3<T> {
+
+        Vec3::new (self.x % rhs.x,
+                   self.y % rhs.y,
+                   self.z % rhs.z)
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    fn clamp (&self, min: &Vec3<T>, max: &Vec3<T>) -> Vec3<T> {
+impl<T> Rem<T> for Vec3<T> where
+    T: Copy + Num + NumCast {
 
-        Vec3::new (util::clamp (self.x, min.x, max.x),
-                   util::clamp (self.y, min.y, max.y),
-                   util::clamp (self.z, min.z, max.z))
+    type Output = Vec3<T>;
+
+    fn rem (self, rhs: T) -> Vec3<T> {
+
+        Vec3::new (self.x % rhs,
+                   self.y % rhs,
+                   self.z % rhs)
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    fn dot (&self, rhs: &Vec3<T>) -> T {
+impl<'a, T> Rem<T> for &'a Vec3<T> where
+    T: Copy + Num + NumCast {
 
-        (self.x * rhs.x) +
-        (self.y * rhs.y) +
-        (self.z * rhs.z)
+    type Output = Vec3<T>;
+
+    fn rem (self, rhs: T) -> Vec3<T> {
+
+        Vec3::new (self.x % rhs,
+                   self.y % rhs,
+                   self.z % rhs)
     }
 }
 
 /*-----------------------------------------------------------------------------------------------*/
 
-impl<T> VecTraitF for Vec3<T> where
-    T: Default + Float {
+impl<T> RemAssign for Vec3<T> where
+    T: Copy + Num + NumCast {
 
-    type ValTypeF = T;
+    fn rem_assign (&mut self, rhs: Vec3<T>) {
 
-    /// Returns the distance between two vectors.
-    ///
-    /// # Examples
-    /// ```
-    /// # use ion_math::vector::{Vec3, VecTraitF};
-    /// let vec01 = Vec3::<f32>::new (1.0, 3.0, 0.0);
-    /// let vec02 = Vec3::<f32>::new (4.0, 9.0, 0.0);
-    ///
-    /// let distance = vec01.distance (&vec02);
-    /// ```
-    fn distance (&self, rhs: &Vec3<T>) -> T {
-        (self - rhs).length ()
+        self.x = self.x % rhs.x;
+        self.y = self.y % rhs.y;
+        self.z = self.z % rhs.z;
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    /// Returns the length of a vector.
-    ///
-    /// # Examples
-    /// ```
-    /// # use ion_math::vector::{Vec3, VecTraitF};
-    /// let vec = Vec3::<f32>::new (1.0, 3.0, 0.0);
-    /// let vec_length = vec.length ();
-    /// ```
-    fn length (&self) -> T {
+impl<T> RemAssign<T> for Vec3<T> where
+    T: Copy + Num + NumCast {
 
-        (self.x * self.x +
-         self.y * self.y +
-         self.z * self.z).sqrt ()
+    fn rem_assign (&mut self, rhs: T) {
+
+        self.x = self.x % rhs;
+        self.y = self.y % rhs;
+        self.z = self.z % rhs;
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    /// Returns a normalized vector.
-    ///
-    /// # Examples
-    /// ```
-    /// # use ion_math::vector::{Vec3, VecTraitF};
-    /// let vec = Vec3::<f32>::new (3.0, 9.0, 0.0);
-    /// let vec_normalized = vec.normalize ();
-    /// ```
-    fn normalize (&self) -> Vec3<T> {
-
-        let length = self.length ();
+impl<T> BitAnd for Vec3<T> where
+    T: BitAnd<Output = T> + Copy + Num + NumCast {
 
-        if length != T::zero () {
+    type Output = Vec3<T>;
 
-            return Vec3::new (self.x / length,
-                              self.y / length,
-                              self.z / length);
-        }
+    fn bitand (self, rhs: Vec3<T>) -> Vec3<T> {
 
-        Vec3::zero ()
+        Vec3::new (self.x & rhs.x,
+                   self.y & rhs.y,
+                   self.z & rhs.z)
     }
 }
 
-/*===============================================================================================*/
-/*------PUBLIC METHODS---------------------------------------------------------------------------*/
-/*===============================================================================================*/
+/*-----------------------------------------------------------------------------------------------*/
 
-impl<T> Vec3<T> where
-    T: Copy + Num + NumCast {
+impl<'a, T> BitAnd<&'a Vec3<T>> for Vec3<T> where
+    T: BitAnd<Output = T> + Copy + Num + NumCast {
 
-    /// Returns the cross product of two vectors.
-    ///
-    /// # Examples
-    /// ```
-    /// # use ion_math::vector::Vec3;
-    /// let vec01 = Vec3::<f32>::new (1, 3, 6);
-    /// let vec02 = Vec3::<f32>::new (4, 9, 2);
-    ///
-    /// let cross_product = vec01.cross (&vec02);
-    /// ```
-    pub fn cross (&self, rhs: &Vec3<T>) -> Vec3<T> {
+    type Output = Vec3<T>;
 
-        Vec3::new ((self.y * rhs.z) - (self.z * rhs.y),
-                   (self.z * rhs.x) - (self.x * rhs.z),
-                   (self.x * rhs.y) - (self.y * rhs.x))
+    fn bitand (self, rhs: &Vec3<T>) -> Vec3<T> {
+
+        Vec3::new (self.x & rhs.x,
+                   self.y & rhs.y,
+                   self.z & rhs.z)
     }
+}
 
-/*===============================================================================================*/
-/*------PUBLIC STATIC METHODS--------------------------------------------------------------------*/
-/*===============================================================================================*/
+/*-----------------------------------------------------------------------------------------------*/
 
-    /// Returns a `Vec3<V>` with a value of (0, 1, 0).
-    ///
-    /// # Examples
-    /// ```
-    /// # use ion_math::vector::Vec3;
-    /// let vec = Vec3::<f32>::up ();
-    /// ```
-    pub fn up () -> Vec3<T> {
-        Vec3::new (0, 1, 0)
+impl<'a, T> BitAnd<Vec3<T>> for &'a Vec3<T> where
+    T: BitAnd<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn bitand (self, rhs: Vec3<T>) -> Vec3<T> {
+
+        Vec3::new (self.x & rhs.x,
+                   self.y & rhs.y,
+                   self.z & rhs.z)
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    /// Returns a `Vec3<T>` with a value of (0, -1, 0).
-    ///
-    /// # Examples
-    /// ```
-    /// # use ion_math::vector::Vec3;
-    /// let vec = Vec3::<f32>::down ();
-    /// ```
-    pub fn down () -> Vec3<T> {
-        Vec3::new (0, -1, 0)
+impl<'a, 'b, T> BitAnd<&'a Vec3<T>> for &'b Vec3<T> where
+    T: BitAnd<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn bitand (self, rhs: &Vec3<T>) -> Vec3<T> {
+
+        Vec3::new (self.x & rhs.x,
+                   self.y & rhs.y,
+                   self.z & rhs.z)
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    /// Returns a `Vec3<T>` with a value of (-1, 0, 0).
-    ///
-    /// # Examples
-    /// ```
-    /// # use ion_math::vector::Vec3;
-    /// let vec = Vec3::<f32>::left ();
-    /// ```
-    pub fn left () -> Vec3<T> {
-        Vec3::new (-1, 0, 0)
+impl<T> BitAnd<T> for Vec3<T> where
+    T: BitAnd<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn bitand (self, rhs: T) -> Vec3<T> {
+
+        Vec3::new (self.x & rhs,
+                   self.y & rhs,
+                   self.z & rhs)
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    /// Returns a `Vec3<T>` with a value of (1, 0, 0).
-    ///
-    /// # Examples
-    /// ```
-    /// # use ion_math::vector::Vec3;
-    /// let vec = Vec3::<f32>::right ();
-    /// ```
-    pub fn right () -> Vec3<T> {
-        Vec3::new (1, 0, 0)
+impl<'a, T> BitAnd<T> for &'a Vec3<T> where
+    T: BitAnd<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn bitand (self, rhs: T) -> Vec3<T> {
+
+        Vec3::new (self.x & rhs,
+                   self.y & rhs,
+                   self.z & rhs)
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    /// Returns a `Vec3<T>` with a value of (0, 0, 1)
-    ///
-    /// # Examples
-    /// ```
-    /// # use ion_math::vector::Vec3;
-    /// let vec = Vec3::<f32>::forward ();
-    /// ```
-    pub fn forward () -> Vec3<T> {
-        Vec3::new (0, 0, 1)
+impl<T> BitAndAssign for Vec3<T> where
+    T: BitAnd<Output = T> + Copy + Num + NumCast {
+
+    fn bitand_assign (&mut self, rhs: Vec3<T>) {
+
+        self.x = self.x & rhs.x;
+        self.y = self.y & rhs.y;
+        self.z = self.z & rhs.z;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> BitAndAssign<T> for Vec3<T> where
+    T: BitAnd<Output = T> + Copy + Num + NumCast {
+
+    fn bitand_assign (&mut self, rhs: T) {
+
+        self.x = self.x & rhs;
+        self.y = self.y & rhs;
+        self.z = self.z & rhs;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> BitOr for Vec3<T> where
+    T: BitOr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn bitor (self, rhs: Vec3<T>) -> Vec3<T> {
+
+        Vec3::new (self.x | rhs.x,
+                   self.y | rhs.y,
+                   self.z | rhs.z)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> BitOr<&'a Vec3<T>> for Vec3<T> where
+    T: BitOr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn bitor (self, rhs: &Vec3<T>) -> Vec3<T> {
+
+        Vec3::new (self.x | rhs.x,
+                   self.y | rhs.y,
+                   self.z | rhs.z)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> BitOr<Vec3<T>> for &'a Vec3<T> where
+    T: BitOr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn bitor (self, rhs: Vec3<T>) -> Vec3<T> {
+
+        Vec3::new (self.x | rhs.x,
+                   self.y | rhs.y,
+                   self.z | rhs.z)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> BitOr<&'a Vec3<T>> for &'b Vec3<T> where
+    T: BitOr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn bitor (self, rhs: &Vec3<T>) -> Vec3<T> {
+
+        Vec3::new (self.x | rhs.x,
+                   self.y | rhs.y,
+                   self.z | rhs.z)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> BitOr<T> for Vec3<T> where
+    T: BitOr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn bitor (self, rhs: T) -> Vec3<T> {
+
+        Vec3::new (self.x | rhs,
+                   self.y | rhs,
+                   self.z | rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> BitOr<T> for &'a Vec3<T> where
+    T: BitOr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn bitor (self, rhs: T) -> Vec3<T> {
+
+        Vec3::new (self.x | rhs,
+                   self.y | rhs,
+                   self.z | rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> BitOrAssign for Vec3<T> where
+    T: BitOr<Output = T> + Copy + Num + NumCast {
+
+    fn bitor_assign (&mut self, rhs: Vec3<T>) {
+
+        self.x = self.x | rhs.x;
+        self.y = self.y | rhs.y;
+        self.z = self.z | rhs.z;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> BitOrAssign<T> for Vec3<T> where
+    T: BitOr<Output = T> + Copy + Num + NumCast {
+
+    fn bitor_assign (&mut self, rhs: T) {
+
+        self.x = self.x | rhs;
+        self.y = self.y | rhs;
+        self.z = self.z | rhs;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> BitXor for Vec3<T> where
+    T: BitXor<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn bitxor (self, rhs: Vec3<T>) -> Vec3<T> {
+
+        Vec3::new (self.x ^ rhs.x,
+                   self.y ^ rhs.y,
+                   self.z ^ rhs.z)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> BitXor<&'a Vec3<T>> for Vec3<T> where
+    T: BitXor<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn bitxor (self, rhs: &Vec3<T>) -> Vec3<T> {
+
+        Vec3::new (self.x ^ rhs.x,
+                   self.y ^ rhs.y,
+                   self.z ^ rhs.z)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> BitXor<Vec3<T>> for &'a Vec3<T> where
+    T: BitXor<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn bitxor (self, rhs: Vec3<T>) -> Vec3<T> {
+
+        Vec3::new (self.x ^ rhs.x,
+                   self.y ^ rhs.y,
+                   self.z ^ rhs.z)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> BitXor<&'a Vec3<T>> for &'b Vec3<T> where
+    T: BitXor<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn bitxor (self, rhs: &Vec3<T>) -> Vec3<T> {
+
+        Vec3::new (self.x ^ rhs.x,
+                   self.y ^ rhs.y,
+                   self.z ^ rhs.z)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> BitXor<T> for Vec3<T> where
+    T: BitXor<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn bitxor (self, rhs: T) -> Vec3<T> {
+
+        Vec3::new (self.x ^ rhs,
+                   self.y ^ rhs,
+                   self.z ^ rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> BitXor<T> for &'a Vec3<T> where
+    T: BitXor<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn bitxor (self, rhs: T) -> Vec3<T> {
+
+        Vec3::new (self.x ^ rhs,
+                   self.y ^ rhs,
+                   self.z ^ rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> BitXorAssign for Vec3<T> where
+    T: BitXor<Output = T> + Copy + Num + NumCast {
+
+    fn bitxor_assign (&mut self, rhs: Vec3<T>) {
+
+        self.x = self.x ^ rhs.x;
+        self.y = self.y ^ rhs.y;
+        self.z = self.z ^ rhs.z;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> BitXorAssign<T> for Vec3<T> where
+    T: BitXor<Output = T> + Copy + Num + NumCast {
+
+    fn bitxor_assign (&mut self, rhs: T) {
+
+        self.x = self.x ^ rhs;
+        self.y = self.y ^ rhs;
+        self.z = self.z ^ rhs;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Shl for Vec3<T> where
+    T: Shl<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn shl (self, rhs: Vec3<T>) -> Vec3<T> {
+
+        Vec3::new (self.x << rhs.x,
+                   self.y << rhs.y,
+                   self.z << rhs.z)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Shl<&'a Vec3<T>> for Vec3<T> where
+    T: Shl<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn shl (self, rhs: &Vec3<T>) -> Vec3<T> {
+
+        Vec3::new (self.x << rhs.x,
+                   self.y << rhs.y,
+                   self.z << rhs.z)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Shl<Vec3<T>> for &'a Vec3<T> where
+    T: Shl<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn shl (self, rhs: Vec3<T>) -> Vec3<T> {
+
+        Vec3::new (self.x << rhs.x,
+                   self.y << rhs.y,
+                   self.z << rhs.z)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> Shl<&'a Vec3<T>> for &'b Vec3<T> where
+    T: Shl<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn shl (self, rhs: &Vec3<T>) -> Vec3<T> {
+
+        Vec3::new (self.x << rhs.x,
+                   self.y << rhs.y,
+                   self.z << rhs.z)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Shl<T> for Vec3<T> where
+    T: Shl<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn shl (self, rhs: T) -> Vec3<T> {
+
+        Vec3::new (self.x << rhs,
+                   self.y << rhs,
+                   self.z << rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Shl<T> for &'a Vec3<T> where
+    T: Shl<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn shl (self, rhs: T) -> Vec3<T> {
+
+        Vec3::new (self.x << rhs,
+                   self.y << rhs,
+                   self.z << rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> ShlAssign for Vec3<T> where
+    T: Shl<Output = T> + Copy + Num + NumCast {
+
+    fn shl_assign (&mut self, rhs: Vec3<T>) {
+
+        self.x = self.x << rhs.x;
+        self.y = self.y << rhs.y;
+        self.z = self.z << rhs.z;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> ShlAssign<T> for Vec3<T> where
+    T: Shl<Output = T> + Copy + Num + NumCast {
+
+    fn shl_assign (&mut self, rhs: T) {
+
+        self.x = self.x << rhs;
+        self.y = self.y << rhs;
+        self.z = self.z << rhs;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Shr for Vec3<T> where
+    T: Shr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn shr (self, rhs: Vec3<T>) -> Vec3<T> {
+
+        Vec3::new (self.x >> rhs.x,
+                   self.y >> rhs.y,
+                   self.z >> rhs.z)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Shr<&'a Vec3<T>> for Vec3<T> where
+    T: Shr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn shr (self, rhs: &Vec3<T>) -> Vec3<T> {
+
+        Vec3::new (self.x >> rhs.x,
+                   self.y >> rhs.y,
+                   self.z >> rhs.z)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Shr<Vec3<T>> for &'a Vec3<T> where
+    T: Shr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn shr (self, rhs: Vec3<T>) -> Vec3<T> {
+
+        Vec3::new (self.x >> rhs.x,
+                   self.y >> rhs.y,
+                   self.z >> rhs.z)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> Shr<&'a Vec3<T>> for &'b Vec3<T> where
+    T: Shr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn shr (self, rhs: &Vec3<T>) -> Vec3<T> {
+
+        Vec3::new (self.x >> rhs.x,
+                   self.y >> rhs.y,
+                   self.z >> rhs.z)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Shr<T> for Vec3<T> where
+    T: Shr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn shr (self, rhs: T) -> Vec3<T> {
+
+        Vec3::new (self.x >> rhs,
+                   self.y >> rhs,
+                   self.z >> rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Shr<T> for &'a Vec3<T> where
+    T: Shr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn shr (self, rhs: T) -> Vec3<T> {
+
+        Vec3::new (self.x >> rhs,
+                   self.y >> rhs,
+                   self.z >> rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> ShrAssign for Vec3<T> where
+    T: Shr<Output = T> + Copy + Num + NumCast {
+
+    fn shr_assign (&mut self, rhs: Vec3<T>) {
+
+        self.x = self.x >> rhs.x;
+        self.y = self.y >> rhs.y;
+        self.z = self.z >> rhs.z;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> ShrAssign<T> for Vec3<T> where
+    T: Shr<Output = T> + Copy + Num + NumCast {
+
+    fn shr_assign (&mut self, rhs: T) {
+
+        self.x = self.x >> rhs;
+        self.y = self.y >> rhs;
+        self.z = self.z >> rhs;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Not for Vec3<T> where
+    T: Copy + Not<Output = T> + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn not (self) -> Vec3<T> {
+
+        Vec3::new (!self.x,
+                   !self.y,
+                   !self.z)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Not for &'a Vec3<T> where
+    T: Copy + Not<Output = T> + Num + NumCast {
+
+    type Output = Vec3<T>;
+
+    fn not (self) -> Vec3<T> {
+
+        Vec3::new (!self.x,
+                   !self.y,
+                   !self.z)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl_vec_scalar_lhs_ops! (Vec3 {x, y, z}; i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Index<u8> for Vec3<T> where
+    T: Copy + Num + NumCast {
+
+    type Output = T;
+
+    fn index (&self, index: u8) -> &T {
+
+        match index {
+
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => unreachable! ("Index out of range for Vec3")
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> IndexMut<u8> for Vec3<T> where
+    T: Copy + Num + NumCast {
+
+    fn index_mut (&mut self, index: u8) -> &mut T {
+
+        match index {
+
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => unreachable! ("Index out of range for Vec3")
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Index<usize> for Vec3<T> where
+    T: Copy + Num + NumCast {
+
+    type Output = T;
+
+    fn index (&self, index: usize) -> &T {
+        self.get (index).expect ("Index out of range for Vec3")
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> IndexMut<usize> for Vec3<T> where
+    T: Copy + Num + NumCast {
+
+    fn index_mut (&mut self, index: usize) -> &mut T {
+        self.get_mut (index).expect ("Index out of range for Vec3")
+    }
+}
+
+/*===============================================================================================*/
+/*------TRAIT IMPLEMENTATIONS--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> VecTrait for Vec3<T> where
+    T: Copy + Default + Num + NumCast + PartialOrd {
+
+    type ValType = T;
+
+    fn lerp (start: &Vec3<T>, end: &Vec3<T>, percentage: f32) -> Vec3<T> {
+
+        Vec3::new (util::lerp (start.x, end.x, percentage),
+                   util::lerp (start.y, end.y, percentage),
+                   util::lerp (start.z, end.z, percentage))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn max (lhs: &Vec3<T>, rhs: &Vec3<T>) -> Vec3<T> {
+
+        Vec3::new (util::max (lhs.x, rhs.x),
+                   util::max (lhs.y, rhs.y),
+                   util::max (lhs.z, rhs.z))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn min (lhs: &Vec3<T>, rhs: &Vec3<T>) -> Vec3<T> {
+
+        Vec3::new (util::min (lhs.x, rhs.x),
+                   util::min (lhs.y, rhs.y),
+                   util::min (lhs.z, rhs.z))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn clamp (&self, min: &Vec3<T>, max: &Vec3<T>) -> Vec3<T> {
+
+        Vec3::new (util::clamp (self.x, min.x, max.x),
+                   util::clamp (self.y, min.y, max.y),
+                   util::clamp (self.z, min.z, max.z))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn dot (&self, rhs: &Vec3<T>) -> T {
+
+        (self.x * rhs.x) +
+        (self.y * rhs.y) +
+        (self.z * rhs.z)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> VecTraitF for Vec3<T> where
+    T: Default + Float {
+
+    type ValTypeF = T;
+
+    /// Returns the distance between two vectors.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::{Vec3, VecTraitF};
+    /// let vec01 = Vec3::<f32>::new (1.0, 3.0, 0.0);
+    /// let vec02 = Vec3::<f32>::new (4.0, 9.0, 0.0);
+    ///
+    /// let distance = vec01.distance (&vec02);
+    /// ```
+    fn distance (&self, rhs: &Vec3<T>) -> T {
+        (self - rhs).length ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the length of a vector.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::{Vec3, VecTraitF};
+    /// let vec = Vec3::<f32>::new (1.0, 3.0, 0.0);
+    /// let vec_length = vec.length ();
+    /// ```
+    fn length (&self) -> T {
+
+        (self.x * self.x +
+         self.y * self.y +
+         self.z * self.z).sqrt ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a normalized vector.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::{Vec3, VecTraitF};
+    /// let vec = Vec3::<f32>::new (3.0, 9.0, 0.0);
+    /// let vec_normalized = vec.normalize ();
+    /// ```
+    fn normalize (&self) -> Vec3<T> {
+
+        let length = self.length ();
+
+        if length != T::zero () {
+
+            return Vec3::new (self.x / length,
+                              self.y / length,
+                              self.z / length);
+        }
+
+        Vec3::zero ()
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> ApproxEq for Vec3<T> where
+    T: ApproxEq + Copy + Num + NumCast {
+
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon () -> T::Epsilon {
+        T::default_epsilon ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_relative () -> T::Epsilon {
+        T::default_max_relative ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_ulps () -> u32 {
+        T::default_max_ulps ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn abs_diff_eq (&self, other: &Vec3<T>, epsilon: T::Epsilon) -> bool {
+
+        self.x.abs_diff_eq (&other.x, epsilon) &&
+        self.y.abs_diff_eq (&other.y, epsilon) &&
+        self.z.abs_diff_eq (&other.z, epsilon)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn relative_eq (&self, other: &Vec3<T>, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+
+        self.x.relative_eq (&other.x, epsilon, max_relative) &&
+        self.y.relative_eq (&other.y, epsilon, max_relative) &&
+        self.z.relative_eq (&other.z, epsilon, max_relative)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn ulps_eq (&self, other: &Vec3<T>, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+
+        self.x.ulps_eq (&other.x, epsilon, max_ulps) &&
+        self.y.ulps_eq (&other.y, epsilon, max_ulps) &&
+        self.z.ulps_eq (&other.z, epsilon, max_ulps)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> AsRef<[T]> for Vec3<T> where
+    T: Copy + Num + NumCast {
+
+    fn as_ref (&self) -> &[T] {
+
+        // The struct is repr (C) and only holds `T` values, so the components are contiguous
+        unsafe {slice::from_raw_parts (self as *const Vec3<T> as *const T, 3)}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> AsMut<[T]> for Vec3<T> where
+    T: Copy + Num + NumCast {
+
+    fn as_mut (&mut self) -> &mut [T] {
+
+        // The struct is repr (C) and only holds `T` values, so the components are contiguous
+        unsafe {slice::from_raw_parts_mut (self as *mut Vec3<T> as *mut T, 3)}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> IntoIterator for Vec3<T> where
+    T: Copy + Num + NumCast {
+
+    type Item     = T;
+    type IntoIter = array::IntoIter<T, 3>;
+
+    fn into_iter (self) -> array::IntoIter<T, 3> {
+        IntoIterator::into_iter (<[T; 3]>::from (self))
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> IntoIterator for &'a Vec3<T> where
+    T: Copy + Num + NumCast {
+
+    type Item     = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter (self) -> slice::Iter<'a, T> {
+        self.iter ()
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> IntoIterator for &'a mut Vec3<T> where
+    T: Copy + Num + NumCast {
+
+    type Item     = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter (self) -> slice::IterMut<'a, T> {
+        self.iter_mut ()
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Sum for Vec3<T> where
+    T: Copy + Num + NumCast {
+
+    fn sum<I> (iter: I) -> Vec3<T> where
+        I: Iterator<Item = Vec3<T>> {
+
+        iter.fold (Vec3::from (0), |acc, vec| acc + vec)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Sum<&'a Vec3<T>> for Vec3<T> where
+    T: Copy + Num + NumCast {
+
+    fn sum<I> (iter: I) -> Vec3<T> where
+        I: Iterator<Item = &'a Vec3<T>> {
+
+        iter.fold (Vec3::from (0), |acc, vec| acc + vec)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Product for Vec3<T> where
+    T: Copy + Num + NumCast {
+
+    fn product<I> (iter: I) -> Vec3<T> where
+        I: Iterator<Item = Vec3<T>> {
+
+        iter.fold (Vec3::from (1), |acc, vec| acc * vec)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Product<&'a Vec3<T>> for Vec3<T> where
+    T: Copy + Num + NumCast {
+
+    fn product<I> (iter: I) -> Vec3<T> where
+        I: Iterator<Item = &'a Vec3<T>> {
+
+        iter.fold (Vec3::from (1), |acc, vec| acc * vec)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> fmt::Display for Vec3<T> where
+    T: Copy + fmt::Display + Num + NumCast {
+
+    /// Formats the vector as `(x, y, z)`, honouring the precision flag.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec3;
+    /// let vec = Vec3::<f32>::new (1, 2, 3);
+    /// assert_eq! (format! ("{:.1}", vec), "(1.0, 2.0, 3.0)");
+    /// ```
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        parse::fmt_components (f, self.as_ref ())
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> FromStr for Vec3<T> where
+    T: Copy + FromStr + Num + NumCast {
+
+    type Err = ParseError;
+
+    /// Parses a vector from a string such as `(1, 2, 3)` or `1 2 3`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec3;
+    /// let vec: Vec3<f32> = "(1, 2, 3)".parse ().unwrap ();
+    /// assert_eq! (vec, Vec3::new (1, 2, 3));
+    /// ```
+    fn from_str (s: &str) -> Result<Vec3<T>, ParseError> {
+
+        let c = parse::parse_components (s, 3)?;
+        Ok (Vec3 {x: c[0], y: c[1], z: c[2]})
+    }
+}
+
+/*===============================================================================================*/
+/*------PUBLIC METHODS---------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Vec3<T> where
+    T: Copy + Num + NumCast {
+
+    /// Returns a reference to a component, or `None` if the index is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec3;
+    /// let vec = Vec3::<f32>::new (1, 2, 3);
+    ///
+    /// assert_eq! (vec.get (1), Some (&2.0));
+    /// assert_eq! (vec.get (3), None);
+    /// ```
+    pub fn get (&self, index: usize) -> Option<&T> {
+        self.as_ref ().get (index)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a mutable reference to a component, or `None` if the index is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec3;
+    /// let mut vec = Vec3::<f32>::new (1, 2, 3);
+    ///
+    /// if let Some (x) = vec.get_mut (0) {
+    ///     *x = 10.0;
+    /// }
+    ///
+    /// vec[0usize] = 1.0;
+    /// assert_eq! (vec[1usize] * 2.0, 4.0);
+    /// ```
+    pub fn get_mut (&mut self, index: usize) -> Option<&mut T> {
+        self.as_mut ().get_mut (index)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns an iterator over the vector components.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec3;
+    /// let vec = Vec3::<i32>::new (1, 2, 3);
+    /// let sum: i32 = vec.iter ().sum ();
+    /// ```
+    pub fn iter (&self) -> slice::Iter<'_, T> {
+        self.as_ref ().iter ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a mutable iterator over the vector components.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec3;
+    /// let mut vec = Vec3::<i32>::new (1, 2, 3);
+    ///
+    /// for component in vec.iter_mut () {
+    ///     *component *= 2;
+    /// }
+    /// ```
+    pub fn iter_mut (&mut self) -> slice::IterMut<'_, T> {
+        self.as_mut ().iter_mut ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a new vector with a function applied to each component.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec3;
+    /// let vec = Vec3::<f32>::new (1, 2, 3);
+    /// let vec_squared = vec.map (|c| c * c);
+    /// ```
+    pub fn map<U, F> (&self, mut f: F) -> Vec3<U> where
+        U: Copy + Num + NumCast,
+        F: FnMut (T) -> U {
+
+        Vec3 {x: f (self.x), y: f (self.y), z: f (self.z)}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a new vector with a function applied to each pair of components.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec3;
+    /// let vec01 = Vec3::<i32>::new (1, 2, 3);
+    /// let vec02 = Vec3::<i32>::new (4, 5, 6);
+    ///
+    /// let vec_max = vec01.zip_map (&vec02, |a, b| if a > b {a} else {b});
+    /// ```
+    pub fn zip_map<U, F> (&self, other: &Vec3<T>, mut f: F) -> Vec3<U> where
+        U: Copy + Num + NumCast,
+        F: FnMut (T, T) -> U {
+
+        Vec3 {x: f (self.x, other.x), y: f (self.y, other.y), z: f (self.z, other.z)}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the cross product of two vectors.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec3;
+    /// let vec01 = Vec3::<f32>::new (1, 3, 6);
+    /// let vec02 = Vec3::<f32>::new (4, 9, 2);
+    ///
+    /// let cross_product = vec01.cross (&vec02);
+    /// ```
+    pub fn cross (&self, rhs: &Vec3<T>) -> Vec3<T> {
+
+        Vec3::new ((self.y * rhs.z) - (self.z * rhs.y),
+                   (self.z * rhs.x) - (self.x * rhs.z),
+                   (self.x * rhs.y) - (self.y * rhs.x))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a `Vec4<T>` with the components of the vector and the given w.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::{Vec3, Vec4};
+    /// let vec = Vec3::<f32>::new (1, 2, 3).extend (1.0);
+    ///
+    /// assert_eq! (vec, Vec4::new (1, 2, 3, 1));
+    /// ```
+    pub fn extend (&self, w: T) -> Vec4<T> {
+        Vec4 {x: self.x, y: self.y, z: self.z, w}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the scalar triple product `self · (b × c)`.
+    ///
+    /// This is the signed volume of the parallelepiped spanned by the three vectors.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec3;
+    /// let volume = Vec3::<i32>::new (1, 0, 0).scalar_triple (&Vec3::new (0, 1, 0),
+    ///                                                         &Vec3::new (0, 0, 1));
+    /// assert_eq! (volume, 1);
+    /// ```
+    pub fn scalar_triple (&self, b: &Vec3<T>, c: &Vec3<T>) -> T {
+
+        let cross = b.cross (c);
+
+        (self.x * cross.x) +
+        (self.y * cross.y) +
+        (self.z * cross.z)
+    }
+
+/*===============================================================================================*/
+/*------PUBLIC STATIC METHODS--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Returns a `Vec3<V>` with a value of (0, 1, 0).
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec3;
+    /// let vec = Vec3::<f32>::up ();
+    /// ```
+    pub fn up () -> Vec3<T> {
+        Vec3::new (0, 1, 0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a `Vec3<T>` with a value of (0, -1, 0).
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec3;
+    /// let vec = Vec3::<f32>::down ();
+    /// ```
+    pub fn down () -> Vec3<T> {
+        Vec3::new (0, -1, 0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a `Vec3<T>` with a value of (-1, 0, 0).
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec3;
+    /// let vec = Vec3::<f32>::left ();
+    /// ```
+    pub fn left () -> Vec3<T> {
+        Vec3::new (-1, 0, 0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a `Vec3<T>` with a value of (1, 0, 0).
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec3;
+    /// let vec = Vec3::<f32>::right ();
+    /// ```
+    pub fn right () -> Vec3<T> {
+        Vec3::new (1, 0, 0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a `Vec3<T>` with a value of (0, 0, 1)
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec3;
+    /// let vec = Vec3::<f32>::forward ();
+    /// ```
+    pub fn forward () -> Vec3<T> {
+        Vec3::new (0, 0, 1)
     }
 
 /*-----------------------------------------------------------------------------------------------*/
@@ -874,3 +2044,294 @@ impl<T> Vec3<T> where
         Vec3::from (0)
     }
 }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+// `Ord` also provides `max`, `min` and `clamp`, which compare lexicographically.
+// These shadow them so the component-wise `VecTrait` versions keep working for integer vectors.
+impl<T> Vec3<T> where
+    T: Copy + Default + Num + NumCast + PartialOrd {
+
+    /// Returns the largest components of two vectors.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec3;
+    /// let vec = Vec3::max (&Vec3::<i32>::new (1, 5, 3), &Vec3::new (2, 0, 3));
+    ///
+    /// assert_eq! (vec, Vec3::new (2, 5, 3));
+    /// ```
+    pub fn max (lhs: &Vec3<T>, rhs: &Vec3<T>) -> Vec3<T> {
+        VecTrait::max (lhs, rhs)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the smallest components of two vectors.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec3;
+    /// let vec = Vec3::min (&Vec3::<i32>::new (1, 5, 3), &Vec3::new (2, 0, 3));
+    ///
+    /// assert_eq! (vec, Vec3::new (1, 0, 3));
+    /// ```
+    pub fn min (lhs: &Vec3<T>, rhs: &Vec3<T>) -> Vec3<T> {
+        VecTrait::min (lhs, rhs)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Clamps each component of a vector between two values.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec3;
+    /// let vec = Vec3::<i32>::new (1, 5, 3).clamp (&Vec3::zero (), &Vec3::from (2));
+    ///
+    /// assert_eq! (vec, Vec3::new (1, 2, 2));
+    /// ```
+    pub fn clamp (self, min: &Vec3<T>, max: &Vec3<T>) -> Vec3<T> {
+        VecTrait::clamp (&self, min, max)
+    }
+}
+
+/*===============================================================================================*/
+/*------PUBLIC FLOAT METHODS---------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Vec3<T> where
+    T: Default + Float {
+
+    /// Returns a unit vector perpendicular to this vector.
+    ///
+    /// The vector does not need to be normalized. A zero vector returns (1, 0, 0).
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::{Vec3, VecTrait};
+    /// let vec = Vec3::<f32>::new (1.0, 2.0, 3.0);
+    /// let perp = vec.any_orthogonal ();
+    ///
+    /// assert! (vec.dot (&perp).abs () < 1e-6);
+    /// ```
+    pub fn any_orthogonal (&self) -> Vec3<T> {
+        self.normalize ().orthonormal_basis ().0
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns two unit vectors that form an orthonormal basis with this vector.
+    ///
+    /// This vector must be normalized. The returned tangent and bitangent, together with
+    /// this vector, form a right-handed basis. <br>
+    /// Uses the branchless construction from Duff et al., "Building an Orthonormal Basis, Revisited".
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec3;
+    /// let normal = Vec3::<f32>::up ();
+    /// let (tangent, bitangent) = normal.orthonormal_basis ();
+    /// ```
+    pub fn orthonormal_basis (&self) -> (Vec3<T>, Vec3<T>) {
+
+        let sign = T::one ().copysign (self.z);
+        let a    = -T::one () / (sign + self.z);
+        let b    = self.x * self.y * a;
+
+        (Vec3::new (T::one () + sign * self.x * self.x * a, sign * b, -sign * self.x),
+         Vec3::new (b, sign + self.y * self.y * a, -self.y))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the spherical coordinates of the vector as (radius, theta, phi).
+    ///
+    /// Theta is the polar angle measured from the positive y-axis, and phi is the azimuth
+    /// measured from the positive x-axis towards the positive z-axis.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec3;
+    /// let vec = Vec3::<f32>::new (0.0, 2.0, 0.0);
+    /// let (radius, theta, phi) = vec.to_spherical ();
+    ///
+    /// assert_eq! (radius, 2.0);
+    /// assert_eq! (theta.value, 0.0);
+    /// ```
+    pub fn to_spherical (&self) -> (T, Rad<T>, Rad<T>) {
+
+        let radius = self.length ();
+
+        if radius == T::zero () {
+            return (radius, Rad::new (T::zero ()), Rad::new (T::zero ()));
+        }
+
+        (radius,
+         Rad::acos (util::clamp (self.y / radius, -T::one (), T::one ())),
+         Rad::atan2 (self.z, self.x))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the cylindrical coordinates of the vector as (radius, phi, height).
+    ///
+    /// The cylinder axis is the y-axis, and phi is the azimuth measured from the
+    /// positive x-axis towards the positive z-axis.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec3;
+    /// let vec = Vec3::<f32>::new (3.0, 5.0, 4.0);
+    /// let (radius, phi, height) = vec.to_cylindrical ();
+    ///
+    /// assert_eq! (radius, 5.0);
+    /// assert_eq! (height, 5.0);
+    /// ```
+    pub fn to_cylindrical (&self) -> (T, Rad<T>, T) {
+
+        (self.x.hypot (self.z),
+         Rad::atan2 (self.z, self.x),
+         self.y)
+    }
+
+/*===============================================================================================*/
+/*------PUBLIC STATIC FLOAT METHODS--------------------------------------------------------------*/
+/*===============================================================================================*/
+
+    /// Orthonormalizes three vectors using the Gram-Schmidt process.
+    ///
+    /// The first vector keeps its direction, the second is made perpendicular to the first,
+    /// and the third is made perpendicular to both.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec3;
+    /// let (x, y, z) = Vec3::<f32>::orthonormalize (&Vec3::new (2.0, 0.0, 0.0),
+    ///                                              &Vec3::new (1.0, 1.0, 0.0),
+    ///                                              &Vec3::new (1.0, 1.0, 1.0));
+    ///
+    /// assert_eq! (y, Vec3::up ());
+    /// ```
+    pub fn orthonormalize (a: &Vec3<T>, b: &Vec3<T>, c: &Vec3<T>) -> (Vec3<T>, Vec3<T>, Vec3<T>) {
+
+        let a = a.normalize ();
+        let b = (b - a * a.dot (b)).normalize ();
+        let c = (c - a * a.dot (c) - b * b.dot (c)).normalize ();
+
+        (a, b, c)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a `Vec3<T>` from spherical coordinates.
+    ///
+    /// Theta is the polar angle measured from the positive y-axis, and phi is the azimuth
+    /// measured from the positive x-axis towards the positive z-axis.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::vector::Vec3;
+    /// let vec = Vec3::<f32>::from_spherical (10.0, Rad::new (0.5), Rad::new (1.2));
+    /// ```
+    pub fn from_spherical (radius: T, theta: Rad<T>, phi: Rad<T>) -> Vec3<T> {
+
+        let (sin_theta, cos_theta) = theta.sin_cos ();
+        let (sin_phi,   cos_phi)   = phi.sin_cos ();
+
+        Vec3::new (radius * sin_theta * cos_phi,
+                   radius * cos_theta,
+                   radius * sin_theta * sin_phi)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a `Vec3<T>` from cylindrical coordinates.
+    ///
+    /// The cylinder axis is the y-axis, and phi is the azimuth measured from the
+    /// positive x-axis towards the positive z-axis.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::angle::Rad;
+    /// # use ion_math::vector::Vec3;
+    /// let vec = Vec3::<f32>::from_cylindrical (2.0, Rad::new (0.7), 3.0);
+    /// ```
+    pub fn from_cylindrical (radius: T, phi: Rad<T>, height: T) -> Vec3<T> {
+
+        let (sin_phi, cos_phi) = phi.sin_cos ();
+
+        Vec3::new (radius * cos_phi,
+                   height,
+                   radius * sin_phi)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Moves a vector towards a target by at most `max_distance`, without overshooting.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec3;
+    /// let current = Vec3::<f32>::new (0.0, 0.0, 0.0);
+    /// let target = Vec3::<f32>::new (0.0, 3.0, 4.0);
+    ///
+    /// assert_eq! (Vec3::move_towards (&current, &target, 1.0), Vec3::new (0.0, 0.6, 0.8));
+    /// ```
+    pub fn move_towards (current: &Vec3<T>, target: &Vec3<T>, max_distance: T) -> Vec3<T> {
+
+        let delta = target - current;
+        let distance = delta.length ();
+
+        if distance <= max_distance || distance == T::zero () {
+            return *target;
+        }
+
+        current + delta * (max_distance / distance)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Smoothly moves a vector towards a target using a critically damped spring.
+    ///
+    /// See `util::smooth_damp` for details. Each component is damped independently.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec3;
+    /// let mut position = Vec3::<f32>::zero ();
+    /// let mut velocity = Vec3::<f32>::zero ();
+    /// let target = Vec3::<f32>::new (0.0, 3.0, 4.0);
+    ///
+    /// position = Vec3::smooth_damp (&position, &target, &mut velocity, 0.3, 1.0 / 60.0);
+    /// ```
+    pub fn smooth_damp (current: &Vec3<T>, target: &Vec3<T>, velocity: &mut Vec3<T>,
+                        smooth_time: T, delta_time: T) -> Vec3<T> {
+
+        Vec3 {x: util::smooth_damp (current.x, target.x, &mut velocity.x, smooth_time, delta_time),
+              y: util::smooth_damp (current.y, target.y, &mut velocity.y, smooth_time, delta_time),
+              z: util::smooth_damp (current.z, target.z, &mut velocity.z, smooth_time, delta_time)}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Moves a vector towards a target with frame-rate independent exponential decay.
+    ///
+    /// See `util::damp` for details.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec3;
+    /// let position = Vec3::<f32>::zero ();
+    /// let target = Vec3::<f32>::new (0.0, 3.0, 4.0);
+    ///
+    /// let position = Vec3::damp (&position, &target, 5.0, 1.0 / 60.0);
+    /// ```
+    pub fn damp (current: &Vec3<T>, target: &Vec3<T>, rate: T, delta_time: T) -> Vec3<T> {
+
+        Vec3 {x: util::damp (current.x, target.x, rate, delta_time),
+              y: util::damp (current.y, target.y, rate, delta_time),
+              z: util::damp (current.z, target.z, rate, delta_time)}
+    }
+}
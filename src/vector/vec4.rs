@@ -20,15 +20,28 @@ extern crate num_traits;
 // Module imports
 use self::num_traits::{Float, Num, NumCast};
 
+use ::approx::ApproxEq;
+use ::parse::{self, ParseError};
 use ::util;
 use ::vector::{Vec2, Vec3, VecTrait, VecTraitF};
 
+use std::{array, slice};
 use std::convert::From;
-use std::ops::{Add,   AddAssign,
-               Sub,   SubAssign,
-               Mul,   MulAssign,
-               Div,   DivAssign,
-               Index, IndexMut};
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{Add,    AddAssign,
+               Sub,    SubAssign,
+               Mul,    MulAssign,
+               Div,    DivAssign,
+               Rem,    RemAssign,
+               BitAnd, BitAndAssign,
+               BitOr,  BitOrAssign,
+               BitXor, BitXorAssign,
+               Shl,    ShlAssign,
+               Shr,    ShrAssign,
+               Index,  IndexMut,
+               Neg,    Not};
+use std::str::FromStr;
 
 /*===============================================================================================*/
 /*------VEC4 STRUCT------------------------------------------------------------------------------*/
@@ -36,7 +49,8 @@ use std::ops::{Add,   AddAssign,
 
 /// The generic Vec4 struct.
 #[cfg_attr (feature = "serde_serialize", derive (Deserialize, Serialize))]
-#[derive (Copy, Clone, Debug, Default, PartialEq)]
+#[derive (Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr (C)]
 pub struct Vec4<T> where
     T: Copy + Num + NumCast {
 
@@ -85,18 +99,7 @@ impl<T> Vec4<T> where
 
 /*-----------------------------------------------------------------------------------------------*/
 
-impl<T, U> From<U> for Vec4<T> where
-    T: Copy + Num + NumCast,
-    U: Copy + Num + NumCast {
-
-    fn from (value: U) -> Vec4<T> {
-
-        Vec4::new (value,
-                   value,
-                   value,
-                   value)
-    }
-}
+impl_vec_from_scalar! (Vec4 {x, y, z, w}; i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
 
 /*-----------------------------------------------------------------------------------------------*/
 
@@ -143,6 +146,46 @@ impl<'a, T, U> From<&'a Vec4<U>> for Vec4<T> where
     }
 }
 
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> From<[T; 4]> for Vec4<T> where
+    T: Copy + Num + NumCast {
+
+    fn from (value: [T; 4]) -> Vec4<T> {
+        Vec4 {x: value[0], y: value[1], z: value[2], w: value[3]}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> From<(T, T, T, T)> for Vec4<T> where
+    T: Copy + Num + NumCast {
+
+    fn from (value: (T, T, T, T)) -> Vec4<T> {
+        Vec4 {x: value.0, y: value.1, z: value.2, w: value.3}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> From<Vec4<T>> for [T; 4] where
+    T: Copy + Num + NumCast {
+
+    fn from (value: Vec4<T>) -> [T; 4] {
+        [value.x, value.y, value.z, value.w]
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> From<Vec4<T>> for (T, T, T, T) where
+    T: Copy + Num + NumCast {
+
+    fn from (value: Vec4<T>) -> (T, T, T, T) {
+        (value.x, value.y, value.z, value.w)
+    }
+}
+
 /*===============================================================================================*/
 /*------OPERATORS--------------------------------------------------------------------------------*/
 /*===============================================================================================*/
@@ -643,252 +686,1491 @@ impl<T> DivAssign<T> for Vec4<T> where
 
 /*-----------------------------------------------------------------------------------------------*/
 
-impl<T> Index<u8> for Vec4<T> where
-    T: Copy + Num + NumCast {
+impl<T> Neg for Vec4<T> where
+    T: Copy + Neg<Output = T> + Num + NumCast {
 
-    type Output = T;
+    type Output = Vec4<T>;
 
-    fn index (&self, index: u8) -> &T {
+    fn neg (self) -> Vec4<T> {
 
-        match index {
+        Vec4::new (-self.x,
+                   -self.y,
+                   -self.z,
+                   -self.w)
+    }
+}
 
-            0 => &self.x,
-            1 => &self.y,
-            2 => &self.z,
-            3 => &self.w,
-            _ => unreachable! ("Index out of range for Vec4")
-        }
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Neg for &'a Vec4<T> where
+    T: Copy + Neg<Output = T> + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn neg (self) -> Vec4<T> {
+
+        Vec4::new (-self.x,
+                   -self.y,
+                   -self.z,
+                   -self.w)
     }
 }
 
 /*-----------------------------------------------------------------------------------------------*/
 
-impl<T> IndexMut<u8> for Vec4<T> where
+impl<T> Rem for Vec4<T> where
     T: Copy + Num + NumCast {
 
-    fn index_mut (&mut self, index: u8) -> &mut T {
+    type Output = Vec4<T>;
 
-        match index {
+    fn rem (self, rhs: Vec4<T>) -> Vec4<T> {
 
-            0 => &mut self.x,
-            1 => &mut self.y,
-            2 => &mut self.z,
-            3 => &mut self.w,
-            _ => unreachable! ("Index out of range for Vec4")
-        }
+        Vec4::new (self.x % rhs.x,
+                   self.y % rhs.y,
+                   self.z % rhs.z,
+                   self.w % rhs.w)
     }
 }
 
-/*===============================================================================================*/
-/*------TRAIT IMPLEMENTATIONS--------------------------------------------------------------------*/
-/*===============================================================================================*/
+/*-----------------------------------------------------------------------------------------------*/
 
-impl<T> VecTrait for Vec4<T> where
-    T: Copy + Default + Num + NumCast + PartialOrd {
+impl<'a, T> Rem<&'a Vec4<T>> for Vec4<T> where
+    T: Copy + Num + NumCast {
 
-    type ValType = T;
+    type Output = Vec4<T>;
 
-    fn lerp (start: &Vec4<T>, end: &Vec4<T>, percentage: f32) -> Vec4<T> {
+    fn rem (self, rhs: &Vec4<T>) -> Vec4<T> {
 
-        Vec4::new (util::lerp (start.x, end.x, percentage),
-                   util::lerp (start.y, end.y, percentage),
-                   util::lerp (start.z, end.z, percentage),
-                   util::lerp (start.w, end.w, percentage))
+        Vec4::new (self.x % rhs.x,
+                   self.y % rhs.y,
+                   self.z % rhs.z,
+                   self.w % rhs.w)
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    fn max (lhs: &Vec4<T>, rhs: &Vec4<T>) -> Vec4<T> {
+impl<'a, T> Rem<Vec4<T>> for &'a Vec4<T> where
+    T: Copy + Num + NumCast {
 
-        Vec4::new (util::max (lhs.x, rhs.x),
-                   util::max (lhs.y, rhs.y),
-                   util::max (lhs.z, rhs.z),
-                   util::max (lhs.w, rhs.w))
+    type Output = Vec4<T>;
+
+    fn rem (self, rhs: Vec4<T>) -> Vec4<T> {
+
+        Vec4::new (self.x % rhs.x,
+                   self.y % rhs.y,
+                   self.z % rhs.z,
+                   self.w % rhs.w)
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    fn min (lhs: &Vec4<T>, rhs: &Vec4<T>) -> Vec4<T> {
+impl<'a, 'b, T> Rem<&'a Vec4<T>> for &'b Vec4<T> where
+    T: Copy + Num + NumCast {
 
-        Vec4::new (util::min (lhs.x, rhs.x),
-                   util::min (lhs.y, rhs.y),
-                   util::min (lhs.z, rhs.z),
-                   util::min (lhs.w, rhs.w))
+    type Output = Vec4<T>;
+
+    fn rem (self, rhs: &Vec4<T>) -> Vec4<T> {
+
+        Vec4::new (self.x % rhs.x,
+                   self.y % rhs.y,
+                   self.z % rhs.z,
+                   self.w % rhs.w)
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    fn clamp (&self, min: &Vec4<T>, max: &Vec4<T>) -> Vec4<T> {
+impl<T> Rem<T> for Vec4<T> where
+    T: Copy + Num + NumCast {
 
-        Vec4::new (util::clamp (self.x, min.x, max.x),
-                   util::clamp (self.y, min.y, max.y),
-                   util::clamp (self.z, min.z, max.z),
-                   util::clamp (self.w, min.w, max.w))
+    type Output = Vec4<T>;
+
+    fn rem (self, rhs: T) -> Vec4<T> {
+
+        Vec4::new (self.x % rhs,
+                   self.y % rhs,
+                   self.z % rhs,
+                   self.w % rhs)
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    fn dot (&self, rhs: &Vec4<T>) -> T {
+impl<'a, T> Rem<T> for &'a Vec4<T> where
+    T: Copy + Num + NumCast {
 
-        (self.x * rhs.x) +
-        (self.y * rhs.y) +
-        (self.z * rhs.z) +
-        (self.w * rhs.w)
+    type Output = Vec4<T>;
+
+    fn rem (self, rhs: T) -> Vec4<T> {
+
+        Vec4::new (self.x % rhs,
+                   self.y % rhs,
+                   self.z % rhs,
+                   self.w % rhs)
     }
 }
 
 /*-----------------------------------------------------------------------------------------------*/
 
-impl<T> VecTraitF for Vec4<T> where
-    T: Default + Float {
+impl<T> RemAssign for Vec4<T> where
+    T: Copy + Num + NumCast {
 
-    type ValTypeF = T;
+    fn rem_assign (&mut self, rhs: Vec4<T>) {
 
-    /// Returns the distance between two vectors.
-    ///
-    /// # Examples
-    /// ```
-    /// # use ion_math::vector::{Vec4, VecTraitF};
-    /// let vec01 = Vec4::<f32>::new (1.0, 3.0, 0.0, 4.3);
-    /// let vec02 = Vec4::<f32>::new (4.0, 9.0, 0.0, 1.0);
-    ///
-    /// let distance = vec01.distance (&vec02);
-    /// ```
-    fn distance (&self, rhs: &Vec4<T>) -> T {
-        (self - rhs).length ()
+        self.x = self.x % rhs.x;
+        self.y = self.y % rhs.y;
+        self.z = self.z % rhs.z;
+        self.w = self.w % rhs.w;
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    /// Returns the length of a vector.
-    ///
-    /// # Examples
-    /// ```
-    /// # use ion_math::vector::{Vec4, VecTraitF};
-    /// let vec = Vec4::<f32>::new (1.0, 3.0, 0.0, 6.0);
-    /// let vec_length = vec.length ();
-    /// ```
-    fn length (&self) -> T {
+impl<T> RemAssign<T> for Vec4<T> where
+    T: Copy + Num + NumCast {
 
-        (self.x * self.x +
-         self.y * self.y +
-         self.z * self.z +
-         self.w * self.w).sqrt ()
+    fn rem_assign (&mut self, rhs: T) {
+
+        self.x = self.x % rhs;
+        self.y = self.y % rhs;
+        self.z = self.z % rhs;
+        self.w = self.w % rhs;
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    /// Returns a normalized vector.
-    ///
-    /// # Examples
-    /// ```
-    /// # use ion_math::vector::{Vec4, VecTraitF};
-    /// let vec = Vec4::<f32>::new (3.0, 9.0, 0.0, 4.0);
-    /// let vec_normalized = vec.normalize ();
-    /// ```
-    fn normalize (&self) -> Vec4<T> {
-
-        let length = self.length ();
+impl<T> BitAnd for Vec4<T> where
+    T: BitAnd<Output = T> + Copy + Num + NumCast {
 
-        if length != T::zero () {
+    type Output = Vec4<T>;
 
-            return Vec4::new (self.x / length,
-                              self.y / length,
-                              self.z / length,
-                              self.w / length);
-        }
+    fn bitand (self, rhs: Vec4<T>) -> Vec4<T> {
 
-        Vec4::zero ()
+        Vec4::new (self.x & rhs.x,
+                   self.y & rhs.y,
+                   self.z & rhs.z,
+                   self.w & rhs.w)
     }
 }
 
-/*===============================================================================================*/
-/*------PUBLIC STATIC METHODS--------------------------------------------------------------------*/
-/*===============================================================================================*/
+/*-----------------------------------------------------------------------------------------------*/
 
-impl<T> Vec4<T> where
-    T: Copy + Num + NumCast {
+impl<'a, T> BitAnd<&'a Vec4<T>> for Vec4<T> where
+    T: BitAnd<Output = T> + Copy + Num + NumCast {
 
-    /// Returns a `Vec4<V>` with a value of (0, 1, 0, 0).
-    ///
-    /// # Examples
-    /// ```
-    /// # use ion_math::vector::Vec4;
-    /// let vec = Vec4::<f32>::up ();
-    /// ```
-    pub fn up () -> Vec4<T> {
-        Vec4::new (0, 1, 0, 0)
-    }
+    type Output = Vec4<T>;
 
-/*-----------------------------------------------------------------------------------------------*/
+    fn bitand (self, rhs: &Vec4<T>) -> Vec4<T> {
 
-    /// Returns a `Vec4<V>` with a value of (0, -1, 0, 0).
-    ///
-    /// # Examples
-    /// ```
-    /// # use ion_math::vector::Vec4;
-    /// let vec = Vec4::<f32>::down ();
-    /// ```
-    pub fn down () -> Vec4<T> {
-        Vec4::new (0, -1, 0, 0)
+        Vec4::new (self.x & rhs.x,
+                   self.y & rhs.y,
+                   self.z & rhs.z,
+                   self.w & rhs.w)
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    /// Returns a `Vec4<V>` with a value of (0, -1, 0, 0).
-    ///
-    /// # Examples
-    /// ```
-    /// # use ion_math::vector::Vec4;
-    /// let vec = Vec4::<f32>::left ();
-    /// ```
-    pub fn left () -> Vec4<T> {
-        Vec4::new (-1, 0, 0, 0)
+impl<'a, T> BitAnd<Vec4<T>> for &'a Vec4<T> where
+    T: BitAnd<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn bitand (self, rhs: Vec4<T>) -> Vec4<T> {
+
+        Vec4::new (self.x & rhs.x,
+                   self.y & rhs.y,
+                   self.z & rhs.z,
+                   self.w & rhs.w)
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    /// Returns a `Vec4<V>` with a value of (0, 1, 0, 0).
-    ///
-    /// # Examples
-    /// ```
-    /// # use ion_math::vector::Vec4;
-    /// let vec = Vec4::<f32>::right ();
-    /// ```
-    pub fn right () -> Vec4<T> {
-        Vec4::new (1, 0, 0, 0)
+impl<'a, 'b, T> BitAnd<&'a Vec4<T>> for &'b Vec4<T> where
+    T: BitAnd<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn bitand (self, rhs: &Vec4<T>) -> Vec4<T> {
+
+        Vec4::new (self.x & rhs.x,
+                   self.y & rhs.y,
+                   self.z & rhs.z,
+                   self.w & rhs.w)
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    /// Returns a `Vec4<V>` with a value of (0, 0, 1, 0)
-    ///
-    /// # Examples
-    /// ```
-    /// # use ion_math::vector::Vec4;
-    /// let vec = Vec4::<f32>::forward ();
-    /// ```
-    pub fn forward () -> Vec4<T> {
-        Vec4::new (0, 0, 1, 0)
+impl<T> BitAnd<T> for Vec4<T> where
+    T: BitAnd<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn bitand (self, rhs: T) -> Vec4<T> {
+
+        Vec4::new (self.x & rhs,
+                   self.y & rhs,
+                   self.z & rhs,
+                   self.w & rhs)
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    /// Returns a `Vec4<V>` with a value of (0, 0, -1, 0)
-    ///
-    /// # Examples
-    /// ```
-    /// # use ion_math::vector::Vec4;
-    /// let vec = Vec4::<f32>::back ();
-    /// ```
-    pub fn back () -> Vec4<T> {
-        Vec4::new (0, 0, -1, 0)
+impl<'a, T> BitAnd<T> for &'a Vec4<T> where
+    T: BitAnd<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn bitand (self, rhs: T) -> Vec4<T> {
+
+        Vec4::new (self.x & rhs,
+                   self.y & rhs,
+                   self.z & rhs,
+                   self.w & rhs)
     }
+}
 
 /*-----------------------------------------------------------------------------------------------*/
 
-    /// Returns a `Vec4<T>` with a value of (0, 0, 0, 0).
-    ///
+impl<T> BitAndAssign for Vec4<T> where
+    T: BitAnd<Output = T> + Copy + Num + NumCast {
+
+    fn bitand_assign (&mut self, rhs: Vec4<T>) {
+
+        self.x = self.x & rhs.x;
+        self.y = self.y & rhs.y;
+        self.z = self.z & rhs.z;
+        self.w = self.w & rhs.w;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> BitAndAssign<T> for Vec4<T> where
+    T: BitAnd<Output = T> + Copy + Num + NumCast {
+
+    fn bitand_assign (&mut self, rhs: T) {
+
+        self.x = self.x & rhs;
+        self.y = self.y & rhs;
+        self.z = self.z & rhs;
+        self.w = self.w & rhs;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> BitOr for Vec4<T> where
+    T: BitOr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn bitor (self, rhs: Vec4<T>) -> Vec4<T> {
+
+        Vec4::new (self.x | rhs.x,
+                   self.y | rhs.y,
+                   self.z | rhs.z,
+                   self.w | rhs.w)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> BitOr<&'a Vec4<T>> for Vec4<T> where
+    T: BitOr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn bitor (self, rhs: &Vec4<T>) -> Vec4<T> {
+
+        Vec4::new (self.x | rhs.x,
+                   self.y | rhs.y,
+                   self.z | rhs.z,
+                   self.w | rhs.w)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> BitOr<Vec4<T>> for &'a Vec4<T> where
+    T: BitOr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn bitor (self, rhs: Vec4<T>) -> Vec4<T> {
+
+        Vec4::new (self.x | rhs.x,
+                   self.y | rhs.y,
+                   self.z | rhs.z,
+                   self.w | rhs.w)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> BitOr<&'a Vec4<T>> for &'b Vec4<T> where
+    T: BitOr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn bitor (self, rhs: &Vec4<T>) -> Vec4<T> {
+
+        Vec4::new (self.x | rhs.x,
+                   self.y | rhs.y,
+                   self.z | rhs.z,
+                   self.w | rhs.w)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> BitOr<T> for Vec4<T> where
+    T: BitOr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn bitor (self, rhs: T) -> Vec4<T> {
+
+        Vec4::new (self.x | rhs,
+                   self.y | rhs,
+                   self.z | rhs,
+                   self.w | rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> BitOr<T> for &'a Vec4<T> where
+    T: BitOr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn bitor (self, rhs: T) -> Vec4<T> {
+
+        Vec4::new (self.x | rhs,
+                   self.y | rhs,
+                   self.z | rhs,
+                   self.w | rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> BitOrAssign for Vec4<T> where
+    T: BitOr<Output = T> + Copy + Num + NumCast {
+
+    fn bitor_assign (&mut self, rhs: Vec4<T>) {
+
+        self.x = self.x | rhs.x;
+        self.y = self.y | rhs.y;
+        self.z = self.z | rhs.z;
+        self.w = self.w | rhs.w;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> BitOrAssign<T> for Vec4<T> where
+    T: BitOr<Output = T> + Copy + Num + NumCast {
+
+    fn bitor_assign (&mut self, rhs: T) {
+
+        self.x = self.x | rhs;
+        self.y = self.y | rhs;
+        self.z = self.z | rhs;
+        self.w = self.w | rhs;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> BitXor for Vec4<T> where
+    T: BitXor<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn bitxor (self, rhs: Vec4<T>) -> Vec4<T> {
+
+        Vec4::new (self.x ^ rhs.x,
+                   self.y ^ rhs.y,
+                   self.z ^ rhs.z,
+                   self.w ^ rhs.w)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> BitXor<&'a Vec4<T>> for Vec4<T> where
+    T: BitXor<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn bitxor (self, rhs: &Vec4<T>) -> Vec4<T> {
+
+        Vec4::new (self.x ^ rhs.x,
+                   self.y ^ rhs.y,
+                   self.z ^ rhs.z,
+                   self.w ^ rhs.w)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> BitXor<Vec4<T>> for &'a Vec4<T> where
+    T: BitXor<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn bitxor (self, rhs: Vec4<T>) -> Vec4<T> {
+
+        Vec4::new (self.x ^ rhs.x,
+                   self.y ^ rhs.y,
+                   self.z ^ rhs.z,
+                   self.w ^ rhs.w)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> BitXor<&'a Vec4<T>> for &'b Vec4<T> where
+    T: BitXor<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn bitxor (self, rhs: &Vec4<T>) -> Vec4<T> {
+
+        Vec4::new (self.x ^ rhs.x,
+                   self.y ^ rhs.y,
+                   self.z ^ rhs.z,
+                   self.w ^ rhs.w)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> BitXor<T> for Vec4<T> where
+    T: BitXor<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn bitxor (self, rhs: T) -> Vec4<T> {
+
+        Vec4::new (self.x ^ rhs,
+                   self.y ^ rhs,
+                   self.z ^ rhs,
+                   self.w ^ rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> BitXor<T> for &'a Vec4<T> where
+    T: BitXor<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn bitxor (self, rhs: T) -> Vec4<T> {
+
+        Vec4::new (self.x ^ rhs,
+                   self.y ^ rhs,
+                   self.z ^ rhs,
+                   self.w ^ rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> BitXorAssign for Vec4<T> where
+    T: BitXor<Output = T> + Copy + Num + NumCast {
+
+    fn bitxor_assign (&mut self, rhs: Vec4<T>) {
+
+        self.x = self.x ^ rhs.x;
+        self.y = self.y ^ rhs.y;
+        self.z = self.z ^ rhs.z;
+        self.w = self.w ^ rhs.w;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> BitXorAssign<T> for Vec4<T> where
+    T: BitXor<Output = T> + Copy + Num + NumCast {
+
+    fn bitxor_assign (&mut self, rhs: T) {
+
+        self.x = self.x ^ rhs;
+        self.y = self.y ^ rhs;
+        self.z = self.z ^ rhs;
+        self.w = self.w ^ rhs;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Shl for Vec4<T> where
+    T: Shl<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn shl (self, rhs: Vec4<T>) -> Vec4<T> {
+
+        Vec4::new (self.x << rhs.x,
+                   self.y << rhs.y,
+                   self.z << rhs.z,
+                   self.w << rhs.w)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Shl<&'a Vec4<T>> for Vec4<T> where
+    T: Shl<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn shl (self, rhs: &Vec4<T>) -> Vec4<T> {
+
+        Vec4::new (self.x << rhs.x,
+                   self.y << rhs.y,
+                   self.z << rhs.z,
+                   self.w << rhs.w)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Shl<Vec4<T>> for &'a Vec4<T> where
+    T: Shl<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn shl (self, rhs: Vec4<T>) -> Vec4<T> {
+
+        Vec4::new (self.x << rhs.x,
+                   self.y << rhs.y,
+                   self.z << rhs.z,
+                   self.w << rhs.w)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> Shl<&'a Vec4<T>> for &'b Vec4<T> where
+    T: Shl<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn shl (self, rhs: &Vec4<T>) -> Vec4<T> {
+
+        Vec4::new (self.x << rhs.x,
+                   self.y << rhs.y,
+                   self.z << rhs.z,
+                   self.w << rhs.w)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Shl<T> for Vec4<T> where
+    T: Shl<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn shl (self, rhs: T) -> Vec4<T> {
+
+        Vec4::new (self.x << rhs,
+                   self.y << rhs,
+                   self.z << rhs,
+                   self.w << rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Shl<T> for &'a Vec4<T> where
+    T: Shl<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn shl (self, rhs: T) -> Vec4<T> {
+
+        Vec4::new (self.x << rhs,
+                   self.y << rhs,
+                   self.z << rhs,
+                   self.w << rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> ShlAssign for Vec4<T> where
+    T: Shl<Output = T> + Copy + Num + NumCast {
+
+    fn shl_assign (&mut self, rhs: Vec4<T>) {
+
+        self.x = self.x << rhs.x;
+        self.y = self.y << rhs.y;
+        self.z = self.z << rhs.z;
+        self.w = self.w << rhs.w;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> ShlAssign<T> for Vec4<T> where
+    T: Shl<Output = T> + Copy + Num + NumCast {
+
+    fn shl_assign (&mut self, rhs: T) {
+
+        self.x = self.x << rhs;
+        self.y = self.y << rhs;
+        self.z = self.z << rhs;
+        self.w = self.w << rhs;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Shr for Vec4<T> where
+    T: Shr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn shr (self, rhs: Vec4<T>) -> Vec4<T> {
+
+        Vec4::new (self.x >> rhs.x,
+                   self.y >> rhs.y,
+                   self.z >> rhs.z,
+                   self.w >> rhs.w)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Shr<&'a Vec4<T>> for Vec4<T> where
+    T: Shr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn shr (self, rhs: &Vec4<T>) -> Vec4<T> {
+
+        Vec4::new (self.x >> rhs.x,
+                   self.y >> rhs.y,
+                   self.z >> rhs.z,
+                   self.w >> rhs.w)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Shr<Vec4<T>> for &'a Vec4<T> where
+    T: Shr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn shr (self, rhs: Vec4<T>) -> Vec4<T> {
+
+        Vec4::new (self.x >> rhs.x,
+                   self.y >> rhs.y,
+                   self.z >> rhs.z,
+                   self.w >> rhs.w)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, 'b, T> Shr<&'a Vec4<T>> for &'b Vec4<T> where
+    T: Shr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn shr (self, rhs: &Vec4<T>) -> Vec4<T> {
+
+        Vec4::new (self.x >> rhs.x,
+                   self.y >> rhs.y,
+                   self.z >> rhs.z,
+                   self.w >> rhs.w)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Shr<T> for Vec4<T> where
+    T: Shr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn shr (self, rhs: T) -> Vec4<T> {
+
+        Vec4::new (self.x >> rhs,
+                   self.y >> rhs,
+                   self.z >> rhs,
+                   self.w >> rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Shr<T> for &'a Vec4<T> where
+    T: Shr<Output = T> + Copy + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn shr (self, rhs: T) -> Vec4<T> {
+
+        Vec4::new (self.x >> rhs,
+                   self.y >> rhs,
+                   self.z >> rhs,
+                   self.w >> rhs)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> ShrAssign for Vec4<T> where
+    T: Shr<Output = T> + Copy + Num + NumCast {
+
+    fn shr_assign (&mut self, rhs: Vec4<T>) {
+
+        self.x = self.x >> rhs.x;
+        self.y = self.y >> rhs.y;
+        self.z = self.z >> rhs.z;
+        self.w = self.w >> rhs.w;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> ShrAssign<T> for Vec4<T> where
+    T: Shr<Output = T> + Copy + Num + NumCast {
+
+    fn shr_assign (&mut self, rhs: T) {
+
+        self.x = self.x >> rhs;
+        self.y = self.y >> rhs;
+        self.z = self.z >> rhs;
+        self.w = self.w >> rhs;
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Not for Vec4<T> where
+    T: Copy + Not<Output = T> + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn not (self) -> Vec4<T> {
+
+        Vec4::new (!self.x,
+                   !self.y,
+                   !self.z,
+                   !self.w)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Not for &'a Vec4<T> where
+    T: Copy + Not<Output = T> + Num + NumCast {
+
+    type Output = Vec4<T>;
+
+    fn not (self) -> Vec4<T> {
+
+        Vec4::new (!self.x,
+                   !self.y,
+                   !self.z,
+                   !self.w)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl_vec_scalar_lhs_ops! (Vec4 {x, y, z, w}; i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Index<u8> for Vec4<T> where
+    T: Copy + Num + NumCast {
+
+    type Output = T;
+
+    fn index (&self, index: u8) -> &T {
+
+        match index {
+
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            3 => &self.w,
+            _ => unreachable! ("Index out of range for Vec4")
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> IndexMut<u8> for Vec4<T> where
+    T: Copy + Num + NumCast {
+
+    fn index_mut (&mut self, index: u8) -> &mut T {
+
+        match index {
+
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            3 => &mut self.w,
+            _ => unreachable! ("Index out of range for Vec4")
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Index<usize> for Vec4<T> where
+    T: Copy + Num + NumCast {
+
+    type Output = T;
+
+    fn index (&self, index: usize) -> &T {
+        self.get (index).expect ("Index out of range for Vec4")
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> IndexMut<usize> for Vec4<T> where
+    T: Copy + Num + NumCast {
+
+    fn index_mut (&mut self, index: usize) -> &mut T {
+        self.get_mut (index).expect ("Index out of range for Vec4")
+    }
+}
+
+/*===============================================================================================*/
+/*------TRAIT IMPLEMENTATIONS--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> VecTrait for Vec4<T> where
+    T: Copy + Default + Num + NumCast + PartialOrd {
+
+    type ValType = T;
+
+    fn lerp (start: &Vec4<T>, end: &Vec4<T>, percentage: f32) -> Vec4<T> {
+
+        Vec4::new (util::lerp (start.x, end.x, percentage),
+                   util::lerp (start.y, end.y, percentage),
+                   util::lerp (start.z, end.z, percentage),
+                   util::lerp (start.w, end.w, percentage))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn max (lhs: &Vec4<T>, rhs: &Vec4<T>) -> Vec4<T> {
+
+        Vec4::new (util::max (lhs.x, rhs.x),
+                   util::max (lhs.y, rhs.y),
+                   util::max (lhs.z, rhs.z),
+                   util::max (lhs.w, rhs.w))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn min (lhs: &Vec4<T>, rhs: &Vec4<T>) -> Vec4<T> {
+
+        Vec4::new (util::min (lhs.x, rhs.x),
+                   util::min (lhs.y, rhs.y),
+                   util::min (lhs.z, rhs.z),
+                   util::min (lhs.w, rhs.w))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn clamp (&self, min: &Vec4<T>, max: &Vec4<T>) -> Vec4<T> {
+
+        Vec4::new (util::clamp (self.x, min.x, max.x),
+                   util::clamp (self.y, min.y, max.y),
+                   util::clamp (self.z, min.z, max.z),
+                   util::clamp (self.w, min.w, max.w))
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn dot (&self, rhs: &Vec4<T>) -> T {
+
+        (self.x * rhs.x) +
+        (self.y * rhs.y) +
+        (self.z * rhs.z) +
+        (self.w * rhs.w)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> VecTraitF for Vec4<T> where
+    T: Default + Float {
+
+    type ValTypeF = T;
+
+    /// Returns the distance between two vectors.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::{Vec4, VecTraitF};
+    /// let vec01 = Vec4::<f32>::new (1.0, 3.0, 0.0, 4.3);
+    /// let vec02 = Vec4::<f32>::new (4.0, 9.0, 0.0, 1.0);
+    ///
+    /// let distance = vec01.distance (&vec02);
+    /// ```
+    fn distance (&self, rhs: &Vec4<T>) -> T {
+        (self - rhs).length ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the length of a vector.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::{Vec4, VecTraitF};
+    /// let vec = Vec4::<f32>::new (1.0, 3.0, 0.0, 6.0);
+    /// let vec_length = vec.length ();
+    /// ```
+    fn length (&self) -> T {
+
+        (self.x * self.x +
+         self.y * self.y +
+         self.z * self.z +
+         self.w * self.w).sqrt ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a normalized vector.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::{Vec4, VecTraitF};
+    /// let vec = Vec4::<f32>::new (3.0, 9.0, 0.0, 4.0);
+    /// let vec_normalized = vec.normalize ();
+    /// ```
+    fn normalize (&self) -> Vec4<T> {
+
+        let length = self.length ();
+
+        if length != T::zero () {
+
+            return Vec4::new (self.x / length,
+                              self.y / length,
+                              self.z / length,
+                              self.w / length);
+        }
+
+        Vec4::zero ()
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> ApproxEq for Vec4<T> where
+    T: ApproxEq + Copy + Num + NumCast {
+
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon () -> T::Epsilon {
+        T::default_epsilon ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_relative () -> T::Epsilon {
+        T::default_max_relative ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn default_max_ulps () -> u32 {
+        T::default_max_ulps ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn abs_diff_eq (&self, other: &Vec4<T>, epsilon: T::Epsilon) -> bool {
+
+        self.x.abs_diff_eq (&other.x, epsilon) &&
+        self.y.abs_diff_eq (&other.y, epsilon) &&
+        self.z.abs_diff_eq (&other.z, epsilon) &&
+        self.w.abs_diff_eq (&other.w, epsilon)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn relative_eq (&self, other: &Vec4<T>, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+
+        self.x.relative_eq (&other.x, epsilon, max_relative) &&
+        self.y.relative_eq (&other.y, epsilon, max_relative) &&
+        self.z.relative_eq (&other.z, epsilon, max_relative) &&
+        self.w.relative_eq (&other.w, epsilon, max_relative)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    fn ulps_eq (&self, other: &Vec4<T>, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+
+        self.x.ulps_eq (&other.x, epsilon, max_ulps) &&
+        self.y.ulps_eq (&other.y, epsilon, max_ulps) &&
+        self.z.ulps_eq (&other.z, epsilon, max_ulps) &&
+        self.w.ulps_eq (&other.w, epsilon, max_ulps)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> AsRef<[T]> for Vec4<T> where
+    T: Copy + Num + NumCast {
+
+    fn as_ref (&self) -> &[T] {
+
+        // The struct is repr (C) and only holds `T` values, so the components are contiguous
+        unsafe {slice::from_raw_parts (self as *const Vec4<T> as *const T, 4)}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> AsMut<[T]> for Vec4<T> where
+    T: Copy + Num + NumCast {
+
+    fn as_mut (&mut self) -> &mut [T] {
+
+        // The struct is repr (C) and only holds `T` values, so the components are contiguous
+        unsafe {slice::from_raw_parts_mut (self as *mut Vec4<T> as *mut T, 4)}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> IntoIterator for Vec4<T> where
+    T: Copy + Num + NumCast {
+
+    type Item     = T;
+    type IntoIter = array::IntoIter<T, 4>;
+
+    fn into_iter (self) -> array::IntoIter<T, 4> {
+        IntoIterator::into_iter (<[T; 4]>::from (self))
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> IntoIterator for &'a Vec4<T> where
+    T: Copy + Num + NumCast {
+
+    type Item     = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter (self) -> slice::Iter<'a, T> {
+        self.iter ()
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> IntoIterator for &'a mut Vec4<T> where
+    T: Copy + Num + NumCast {
+
+    type Item     = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter (self) -> slice::IterMut<'a, T> {
+        self.iter_mut ()
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Sum for Vec4<T> where
+    T: Copy + Num + NumCast {
+
+    fn sum<I> (iter: I) -> Vec4<T> where
+        I: Iterator<Item = Vec4<T>> {
+
+        iter.fold (Vec4::from (0), |acc, vec| acc + vec)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Sum<&'a Vec4<T>> for Vec4<T> where
+    T: Copy + Num + NumCast {
+
+    fn sum<I> (iter: I) -> Vec4<T> where
+        I: Iterator<Item = &'a Vec4<T>> {
+
+        iter.fold (Vec4::from (0), |acc, vec| acc + vec)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> Product for Vec4<T> where
+    T: Copy + Num + NumCast {
+
+    fn product<I> (iter: I) -> Vec4<T> where
+        I: Iterator<Item = Vec4<T>> {
+
+        iter.fold (Vec4::from (1), |acc, vec| acc * vec)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<'a, T> Product<&'a Vec4<T>> for Vec4<T> where
+    T: Copy + Num + NumCast {
+
+    fn product<I> (iter: I) -> Vec4<T> where
+        I: Iterator<Item = &'a Vec4<T>> {
+
+        iter.fold (Vec4::from (1), |acc, vec| acc * vec)
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> fmt::Display for Vec4<T> where
+    T: Copy + fmt::Display + Num + NumCast {
+
+    /// Formats the vector as `(x, y, z, w)`, honouring the precision flag.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec4;
+    /// let vec = Vec4::<f32>::new (1, 2, 3, 4);
+    /// assert_eq! (format! ("{:.1}", vec), "(1.0, 2.0, 3.0, 4.0)");
+    /// ```
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        parse::fmt_components (f, self.as_ref ())
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+impl<T> FromStr for Vec4<T> where
+    T: Copy + FromStr + Num + NumCast {
+
+    type Err = ParseError;
+
+    /// Parses a vector from a string such as `(1, 2, 3, 4)` or `1 2 3 4`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec4;
+    /// let vec: Vec4<f32> = "(1, 2, 3, 4)".parse ().unwrap ();
+    /// assert_eq! (vec, Vec4::new (1, 2, 3, 4));
+    /// ```
+    fn from_str (s: &str) -> Result<Vec4<T>, ParseError> {
+
+        let c = parse::parse_components (s, 4)?;
+        Ok (Vec4 {x: c[0], y: c[1], z: c[2], w: c[3]})
+    }
+}
+
+/*===============================================================================================*/
+/*------PUBLIC METHODS---------------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Vec4<T> where
+    T: Copy + Num + NumCast {
+
+    /// Returns a reference to a component, or `None` if the index is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec4;
+    /// let vec = Vec4::<f32>::new (1, 2, 3, 4);
+    ///
+    /// assert_eq! (vec.get (1), Some (&2.0));
+    /// assert_eq! (vec.get (4), None);
+    /// ```
+    pub fn get (&self, index: usize) -> Option<&T> {
+        self.as_ref ().get (index)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a mutable reference to a component, or `None` if the index is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec4;
+    /// let mut vec = Vec4::<f32>::new (1, 2, 3, 4);
+    ///
+    /// if let Some (x) = vec.get_mut (0) {
+    ///     *x = 10.0;
+    /// }
+    ///
+    /// vec[0usize] = 1.0;
+    /// assert_eq! (vec[1usize] * 2.0, 4.0);
+    /// ```
+    pub fn get_mut (&mut self, index: usize) -> Option<&mut T> {
+        self.as_mut ().get_mut (index)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns an iterator over the vector components.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec4;
+    /// let vec = Vec4::<i32>::new (1, 2, 3, 4);
+    /// let sum: i32 = vec.iter ().sum ();
+    /// ```
+    pub fn iter (&self) -> slice::Iter<'_, T> {
+        self.as_ref ().iter ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a mutable iterator over the vector components.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec4;
+    /// let mut vec = Vec4::<i32>::new (1, 2, 3, 4);
+    ///
+    /// for component in vec.iter_mut () {
+    ///     *component *= 2;
+    /// }
+    /// ```
+    pub fn iter_mut (&mut self) -> slice::IterMut<'_, T> {
+        self.as_mut ().iter_mut ()
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a new vector with a function applied to each component.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec4;
+    /// let vec = Vec4::<f32>::new (1, 2, 3, 4);
+    /// let vec_squared = vec.map (|c| c * c);
+    /// ```
+    pub fn map<U, F> (&self, mut f: F) -> Vec4<U> where
+        U: Copy + Num + NumCast,
+        F: FnMut (T) -> U {
+
+        Vec4 {x: f (self.x), y: f (self.y), z: f (self.z), w: f (self.w)}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a new vector with a function applied to each pair of components.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec4;
+    /// let vec01 = Vec4::<i32>::new (1, 2, 3, 4);
+    /// let vec02 = Vec4::<i32>::new (4, 5, 6, 7);
+    ///
+    /// let vec_max = vec01.zip_map (&vec02, |a, b| if a > b {a} else {b});
+    /// ```
+    pub fn zip_map<U, F> (&self, other: &Vec4<T>, mut f: F) -> Vec4<U> where
+        U: Copy + Num + NumCast,
+        F: FnMut (T, T) -> U {
+
+        Vec4 {x: f (self.x, other.x), y: f (self.y, other.y), z: f (self.z, other.z), w: f (self.w, other.w)}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the x, y and z components of the vector, discarding w.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::{Vec3, Vec4};
+    /// let vec = Vec4::<f32>::new (1, 2, 3, 4);
+    ///
+    /// assert_eq! (vec.truncate (), Vec3::new (1, 2, 3));
+    /// ```
+    pub fn truncate (&self) -> Vec3<T> {
+        Vec3 {x: self.x, y: self.y, z: self.z}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Divides the x, y and z components by w, converting a homogeneous point <br>
+    /// such as a clip-space position to Cartesian coordinates.
+    ///
+    /// A w of zero describes a point at infinity, so x, y and z are returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::{Vec3, Vec4};
+    /// let clip = Vec4::<f32>::new (2, 4, 6, 2);
+    ///
+    /// assert_eq! (clip.perspective_divide (), Vec3::new (1, 2, 3));
+    /// ```
+    pub fn perspective_divide (&self) -> Vec3<T> {
+
+        if self.w == T::zero () {
+            return self.truncate ();
+        }
+
+        Vec3 {x: self.x / self.w,
+              y: self.y / self.w,
+              z: self.z / self.w}
+    }
+}
+
+/*-----------------------------------------------------------------------------------------------*/
+
+// `Ord` also provides `max`, `min` and `clamp`, which compare lexicographically.
+// These shadow them so the component-wise `VecTrait` versions keep working for integer vectors.
+impl<T> Vec4<T> where
+    T: Copy + Default + Num + NumCast + PartialOrd {
+
+    /// Returns the largest components of two vectors.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec4;
+    /// let vec = Vec4::max (&Vec4::<i32>::new (1, 5, 3, 0), &Vec4::new (2, 0, 3, 0));
+    ///
+    /// assert_eq! (vec, Vec4::new (2, 5, 3, 0));
+    /// ```
+    pub fn max (lhs: &Vec4<T>, rhs: &Vec4<T>) -> Vec4<T> {
+        VecTrait::max (lhs, rhs)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns the smallest components of two vectors.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec4;
+    /// let vec = Vec4::min (&Vec4::<i32>::new (1, 5, 3, 0), &Vec4::new (2, 0, 3, 0));
+    ///
+    /// assert_eq! (vec, Vec4::new (1, 0, 3, 0));
+    /// ```
+    pub fn min (lhs: &Vec4<T>, rhs: &Vec4<T>) -> Vec4<T> {
+        VecTrait::min (lhs, rhs)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Clamps each component of a vector between two values.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec4;
+    /// let vec = Vec4::<i32>::new (1, 5, 3, 0).clamp (&Vec4::zero (), &Vec4::from (2));
+    ///
+    /// assert_eq! (vec, Vec4::new (1, 2, 2, 0));
+    /// ```
+    pub fn clamp (self, min: &Vec4<T>, max: &Vec4<T>) -> Vec4<T> {
+        VecTrait::clamp (&self, min, max)
+    }
+}
+
+/*===============================================================================================*/
+/*------PUBLIC STATIC METHODS--------------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Vec4<T> where
+    T: Copy + Num + NumCast {
+
+    /// Returns a `Vec4<V>` with a value of (0, 1, 0, 0).
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec4;
+    /// let vec = Vec4::<f32>::up ();
+    /// ```
+    pub fn up () -> Vec4<T> {
+        Vec4::new (0, 1, 0, 0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a `Vec4<V>` with a value of (0, -1, 0, 0).
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec4;
+    /// let vec = Vec4::<f32>::down ();
+    /// ```
+    pub fn down () -> Vec4<T> {
+        Vec4::new (0, -1, 0, 0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a `Vec4<V>` with a value of (0, -1, 0, 0).
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec4;
+    /// let vec = Vec4::<f32>::left ();
+    /// ```
+    pub fn left () -> Vec4<T> {
+        Vec4::new (-1, 0, 0, 0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a `Vec4<V>` with a value of (0, 1, 0, 0).
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec4;
+    /// let vec = Vec4::<f32>::right ();
+    /// ```
+    pub fn right () -> Vec4<T> {
+        Vec4::new (1, 0, 0, 0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a `Vec4<V>` with a value of (0, 0, 1, 0)
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec4;
+    /// let vec = Vec4::<f32>::forward ();
+    /// ```
+    pub fn forward () -> Vec4<T> {
+        Vec4::new (0, 0, 1, 0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a `Vec4<V>` with a value of (0, 0, -1, 0)
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec4;
+    /// let vec = Vec4::<f32>::back ();
+    /// ```
+    pub fn back () -> Vec4<T> {
+        Vec4::new (0, 0, -1, 0)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a `Vec4<T>` with a value of (0, 0, 0, 0).
+    ///
     /// # Examples
     /// ```
     /// # use ion_math::vector::Vec4;
@@ -897,4 +2179,110 @@ impl<T> Vec4<T> where
     pub fn zero () -> Vec4<T> {
         Vec4::from (0)
     }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a homogeneous point from a `Vec3<T>`, with a w of 1.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::{Vec3, Vec4};
+    /// let point = Vec4::from_point (&Vec3::<f32>::new (1, 2, 3));
+    ///
+    /// assert_eq! (point, Vec4::new (1, 2, 3, 1));
+    /// ```
+    pub fn from_point (point: &Vec3<T>) -> Vec4<T> {
+        point.extend (T::one ())
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Returns a homogeneous direction from a `Vec3<T>`, with a w of 0.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::{Vec3, Vec4};
+    /// let direction = Vec4::from_direction (&Vec3::<f32>::new (1, 2, 3));
+    ///
+    /// assert_eq! (direction, Vec4::new (1, 2, 3, 0));
+    /// ```
+    pub fn from_direction (direction: &Vec3<T>) -> Vec4<T> {
+        direction.extend (T::zero ())
+    }
+}
+
+/*===============================================================================================*/
+/*------PUBLIC STATIC FLOAT METHODS--------------------------------------------------------------*/
+/*===============================================================================================*/
+
+impl<T> Vec4<T> where
+    T: Default + Float {
+
+    /// Moves a vector towards a target by at most `max_distance`, without overshooting.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec4;
+    /// let current = Vec4::<f32>::new (0.0, 0.0, 0.0, 0.0);
+    /// let target = Vec4::<f32>::new (0.0, 0.0, 3.0, 4.0);
+    ///
+    /// assert_eq! (Vec4::move_towards (&current, &target, 1.0), Vec4::new (0.0, 0.0, 0.6, 0.8));
+    /// ```
+    pub fn move_towards (current: &Vec4<T>, target: &Vec4<T>, max_distance: T) -> Vec4<T> {
+
+        let delta = target - current;
+        let distance = delta.length ();
+
+        if distance <= max_distance || distance == T::zero () {
+            return *target;
+        }
+
+        current + delta * (max_distance / distance)
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Smoothly moves a vector towards a target using a critically damped spring.
+    ///
+    /// See `util::smooth_damp` for details. Each component is damped independently.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec4;
+    /// let mut position = Vec4::<f32>::zero ();
+    /// let mut velocity = Vec4::<f32>::zero ();
+    /// let target = Vec4::<f32>::new (0.0, 0.0, 3.0, 4.0);
+    ///
+    /// position = Vec4::smooth_damp (&position, &target, &mut velocity, 0.3, 1.0 / 60.0);
+    /// ```
+    pub fn smooth_damp (current: &Vec4<T>, target: &Vec4<T>, velocity: &mut Vec4<T>,
+                        smooth_time: T, delta_time: T) -> Vec4<T> {
+
+        Vec4 {x: util::smooth_damp (current.x, target.x, &mut velocity.x, smooth_time, delta_time),
+              y: util::smooth_damp (current.y, target.y, &mut velocity.y, smooth_time, delta_time),
+              z: util::smooth_damp (current.z, target.z, &mut velocity.z, smooth_time, delta_time),
+              w: util::smooth_damp (current.w, target.w, &mut velocity.w, smooth_time, delta_time)}
+    }
+
+/*-----------------------------------------------------------------------------------------------*/
+
+    /// Moves a vector towards a target with frame-rate independent exponential decay.
+    ///
+    /// See `util::damp` for details.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ion_math::vector::Vec4;
+    /// let position = Vec4::<f32>::zero ();
+    /// let target = Vec4::<f32>::new (0.0, 0.0, 3.0, 4.0);
+    ///
+    /// let position = Vec4::damp (&position, &target, 5.0, 1.0 / 60.0);
+    /// ```
+    pub fn damp (current: &Vec4<T>, target: &Vec4<T>, rate: T, delta_time: T) -> Vec4<T> {
+
+        Vec4 {x: util::damp (current.x, target.x, rate, delta_time),
+              y: util::damp (current.y, target.y, rate, delta_time),
+              z: util::damp (current.z, target.z, rate, delta_time),
+              w: util::damp (current.w, target.w, rate, delta_time)}
+    }
 }